@@ -0,0 +1,523 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::{state::{BattleAction, ElementType, KnownMove, PokemonData, TypeChart}, TYPE_NEUTRAL_BPS};
+
+/// Upper bound on simulated rounds so resolution always terminates
+pub const MAX_BATTLE_ROUNDS: u8 = 50;
+
+/// Damage roll range in percent (85% - 100% of base damage)
+const MIN_DAMAGE_ROLL: u64 = 85;
+const DAMAGE_ROLL_SPREAD: u64 = 16;
+
+/// Move power that deals the same damage as a basic attack
+const BASE_MOVE_POWER: u64 = 50;
+
+/// Critical hits deal 150% damage
+const CRIT_CHANCE_PERCENT: u64 = 6;
+const CRIT_DAMAGE_PERCENT: u64 = 150;
+
+/// Chance to dodge an attack, higher when the defender is faster
+const DODGE_CHANCE_PERCENT: u64 = 5;
+const FASTER_DODGE_CHANCE_PERCENT: u64 = 10;
+
+/// Battle-relevant snapshot of a Pokémon's stats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Combatant {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub primary_type: ElementType,
+    pub secondary_type: Option<ElementType>,
+}
+
+impl From<&PokemonData> for Combatant {
+    fn from(pokemon: &PokemonData) -> Self {
+        Self {
+            hp: pokemon.hp,
+            attack: pokemon.attack,
+            defense: pokemon.defense,
+            speed: pokemon.speed,
+            primary_type: pokemon.primary_type,
+            secondary_type: pokemon.secondary_type,
+        }
+    }
+}
+
+/// Result of a simulated battle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BattleOutcome {
+    pub winner_is_player1: bool,
+    pub rounds: u8,
+    pub player1_hp_remaining: u16,
+    pub player2_hp_remaining: u16,
+}
+
+/// Deterministic xorshift64* generator seeded from the battle seed
+pub struct BattleRng {
+    state: u64,
+}
+
+impl BattleRng {
+    pub fn new(seed: &[u8; 32]) -> Self {
+        let state = seed
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .fold(0u64, |acc, word| acc ^ word);
+
+        // xorshift must never be seeded with zero
+        Self {
+            state: if state == 0 { 0x9E37_79B9_7F4A_7C15 } else { state },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform-ish roll in `0..bound`
+    pub fn roll(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+/// Derive the battle seed committed in `create_battle`
+pub fn commit_battle_seed(battle: &Pubkey, player1: &Pubkey, clock: &Clock) -> [u8; 32] {
+    hashv(&[
+        b"battle_seed",
+        battle.as_ref(),
+        player1.as_ref(),
+        &clock.slot.to_le_bytes(),
+        &clock.unix_timestamp.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Mix oracle randomness into the committed battle seed.
+///
+/// Neither player can bias the result: the seed is fixed at creation and the
+/// randomness account is committed at join, before it is revealed. The
+/// outcome is only as fair as the oracle the admin configures.
+pub fn mix_randomness(seed: &[u8; 32], randomness: &[u8; 32]) -> [u8; 32] {
+    hashv(&[b"battle_randomness", seed.as_ref(), randomness.as_ref()]).to_bytes()
+}
+
+/// Per-turn seed for turn-based battles, so each turn rolls independently.
+///
+/// Both players' salts are committed before either is revealed, so neither
+/// can predict the turn's rolls when choosing an action, nor bias them.
+pub fn turn_seed(seed: &[u8; 32], turn: u16, salts: [&[u8; 32]; 2]) -> [u8; 32] {
+    hashv(&[
+        b"battle_turn",
+        seed.as_ref(),
+        &turn.to_le_bytes(),
+        salts[0].as_ref(),
+        salts[1].as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Commitment a player submits in `commit_turn` and opens in `reveal_turn`
+pub fn action_commitment(
+    battle: &Pubkey,
+    turn: u16,
+    action: BattleAction,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        b"battle_action",
+        battle.as_ref(),
+        &turn.to_le_bytes(),
+        &action.to_bytes(),
+        salt.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// The faster Pokémon moves first, ties are broken by the generator
+pub fn player1_moves_first(player1: &Combatant, player2: &Combatant, rng: &mut BattleRng) -> bool {
+    if player1.speed != player2.speed {
+        player1.speed > player2.speed
+    } else {
+        rng.roll(2) == 0
+    }
+}
+
+/// Type multiplier in basis points for an attack, using the attacker's
+/// more effective type against the defender's type(s)
+pub fn type_effectiveness(
+    attacker: &Combatant,
+    defender: &Combatant,
+    type_chart: &TypeChart,
+) -> u64 {
+    let against = |attacking: ElementType| {
+        type_chart.effectiveness(attacking, defender.primary_type, defender.secondary_type)
+    };
+
+    let primary = against(attacker.primary_type);
+    attacker
+        .secondary_type
+        .map_or(primary, |secondary| primary.max(against(secondary)))
+}
+
+/// Base damage before rolls: attack^2 / (attack + defense) keeps damage
+/// proportional to attack while letting defense soften, but never nullify,
+/// incoming hits
+fn base_damage(attacker: &Combatant, defender: &Combatant) -> u64 {
+    let attack = attacker.attack as u64;
+    let defense = defender.defense as u64;
+
+    attack * attack / (attack + defense).max(1)
+}
+
+/// Apply the dodge, damage and critical hit rolls plus type effectiveness
+/// (in basis points) to a base damage value. Returns 0 for a dodge,
+/// otherwise at least 1.
+fn roll_damage(
+    attacker: &Combatant,
+    defender: &Combatant,
+    base: u64,
+    effectiveness: u64,
+    rng: &mut BattleRng,
+) -> u16 {
+    let dodge_chance = if defender.speed > attacker.speed {
+        FASTER_DODGE_CHANCE_PERCENT
+    } else {
+        DODGE_CHANCE_PERCENT
+    };
+    if rng.roll(100) < dodge_chance {
+        return 0;
+    }
+
+    let roll = MIN_DAMAGE_ROLL + rng.roll(DAMAGE_ROLL_SPREAD);
+    let crit = if rng.roll(100) < CRIT_CHANCE_PERCENT {
+        CRIT_DAMAGE_PERCENT
+    } else {
+        100
+    };
+    let damage = (base * roll / 100 * crit / 100 * effectiveness / TYPE_NEUTRAL_BPS as u64).max(1);
+
+    u16::try_from(damage).unwrap_or(u16::MAX)
+}
+
+/// Damage dealt by a basic attack: 0 if dodged, otherwise at least 1
+pub fn compute_damage(
+    attacker: &Combatant,
+    defender: &Combatant,
+    type_chart: &TypeChart,
+    rng: &mut BattleRng,
+) -> u16 {
+    let base = base_damage(attacker, defender);
+    let effectiveness = type_effectiveness(attacker, defender, type_chart);
+
+    roll_damage(attacker, defender, base, effectiveness, rng)
+}
+
+/// Damage dealt by an equipped move, or `None` if the accuracy roll misses.
+///
+/// Scales the basic attack by the move's power and rates type effectiveness
+/// on the move's type instead of the attacker's. A landed move can still be
+/// dodged for 0 damage.
+pub fn compute_move_damage(
+    attacker: &Combatant,
+    defender: &Combatant,
+    known_move: &KnownMove,
+    type_chart: &TypeChart,
+    rng: &mut BattleRng,
+) -> Option<u16> {
+    if rng.roll(100) >= known_move.accuracy as u64 {
+        return None;
+    }
+
+    let base = base_damage(attacker, defender) * known_move.power as u64 / BASE_MOVE_POWER;
+    let effectiveness = type_chart.effectiveness(
+        known_move.element_type,
+        defender.primary_type,
+        defender.secondary_type,
+    );
+
+    Some(roll_damage(attacker, defender, base, effectiveness, rng))
+}
+
+/// Play out one turn of a turn-based battle.
+///
+/// Each side uses its basic attack, or the given move. The faster Pokémon
+/// acts first (ties are broken by the seed) and a fainted Pokémon does not
+/// get to act. Returns the remaining HP of both sides.
+pub fn resolve_turn(
+    player1: &Combatant,
+    player2: &Combatant,
+    moves: [Option<&KnownMove>; 2],
+    hp: [u16; 2],
+    type_chart: &TypeChart,
+    seed: &[u8; 32],
+) -> [u16; 2] {
+    let mut rng = BattleRng::new(seed);
+    let mut hp = hp;
+    let combatants = [player1, player2];
+
+    let player1_first = player1_moves_first(player1, player2, &mut rng);
+    let order: [usize; 2] = if player1_first { [0, 1] } else { [1, 0] };
+
+    for &attacker in order.iter() {
+        let defender = 1 - attacker;
+        if hp[attacker] == 0 {
+            break;
+        }
+
+        let damage = match moves[attacker] {
+            Some(known_move) => compute_move_damage(
+                combatants[attacker],
+                combatants[defender],
+                known_move,
+                type_chart,
+                &mut rng,
+            )
+            .unwrap_or(0),
+            None => compute_damage(combatants[attacker], combatants[defender], type_chart, &mut rng),
+        };
+        hp[defender] = hp[defender].saturating_sub(damage);
+    }
+
+    hp
+}
+
+/// Simulate a full battle between two Pokémon.
+///
+/// The faster Pokémon strikes first each round (ties are broken by the seed)
+/// and damage is scaled by the type chart, with dodge and critical hit rolls
+/// drawn from the seed. If neither side faints within
+/// `MAX_BATTLE_ROUNDS`, the side with the higher share of its starting HP wins.
+pub fn simulate_battle(
+    player1: &Combatant,
+    player2: &Combatant,
+    type_chart: &TypeChart,
+    seed: &[u8; 32],
+) -> BattleOutcome {
+    let mut rng = BattleRng::new(seed);
+    let mut hp = [player1.hp, player2.hp];
+    let combatants = [player1, player2];
+
+    let player1_first = player1_moves_first(player1, player2, &mut rng);
+    let order: [usize; 2] = if player1_first { [0, 1] } else { [1, 0] };
+
+    for round in 1..=MAX_BATTLE_ROUNDS {
+        for &attacker in order.iter() {
+            let defender = 1 - attacker;
+            let damage = compute_damage(combatants[attacker], combatants[defender], type_chart, &mut rng);
+            hp[defender] = hp[defender].saturating_sub(damage);
+
+            if hp[defender] == 0 {
+                return BattleOutcome {
+                    winner_is_player1: attacker == 0,
+                    rounds: round,
+                    player1_hp_remaining: hp[0],
+                    player2_hp_remaining: hp[1],
+                };
+            }
+        }
+    }
+
+    // Compare remaining HP ratios without division: hp1 / max1 vs hp2 / max2
+    let player1_share = hp[0] as u64 * player2.hp as u64;
+    let player2_share = hp[1] as u64 * player1.hp as u64;
+    let winner_is_player1 = if player1_share != player2_share {
+        player1_share > player2_share
+    } else {
+        rng.roll(2) == 0
+    };
+
+    BattleOutcome {
+        winner_is_player1,
+        rounds: MAX_BATTLE_ROUNDS,
+        player1_hp_remaining: hp[0],
+        player2_hp_remaining: hp[1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TYPE_CHART_SIZE;
+
+    fn neutral_chart() -> TypeChart {
+        TypeChart {
+            multipliers: [TYPE_NEUTRAL_BPS; TYPE_CHART_SIZE],
+            bump: 0,
+        }
+    }
+
+    fn combatant(hp: u16, attack: u16, defense: u16, speed: u16) -> Combatant {
+        Combatant {
+            hp,
+            attack,
+            defense,
+            speed,
+            primary_type: ElementType::Normal,
+            secondary_type: None,
+        }
+    }
+
+    fn seed(n: u64) -> [u8; 32] {
+        hashv(&[b"test_seed", &n.to_le_bytes()]).to_bytes()
+    }
+
+    #[test]
+    fn rng_is_deterministic_per_seed() {
+        let mut a = BattleRng::new(&seed(1));
+        let mut b = BattleRng::new(&seed(1));
+        let mut c = BattleRng::new(&seed(2));
+
+        let first: Vec<u64> = (0..32).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..32).map(|_| b.next_u64()).collect();
+        let other: Vec<u64> = (0..32).map(|_| c.next_u64()).collect();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn rng_never_sticks_on_a_zero_seed() {
+        let mut rng = BattleRng::new(&[0; 32]);
+        assert!((0..32).any(|_| rng.next_u64() != 0));
+    }
+
+    #[test]
+    fn simulation_ends_within_round_limit() {
+        let chart = neutral_chart();
+        let player1 = combatant(120, 60, 40, 55);
+        let player2 = combatant(150, 45, 55, 40);
+
+        for n in 0..200 {
+            let outcome = simulate_battle(&player1, &player2, &chart, &seed(n));
+
+            assert!(outcome.rounds >= 1 && outcome.rounds <= MAX_BATTLE_ROUNDS);
+            if outcome.rounds < MAX_BATTLE_ROUNDS {
+                let loser_hp = if outcome.winner_is_player1 {
+                    outcome.player2_hp_remaining
+                } else {
+                    outcome.player1_hp_remaining
+                };
+                assert_eq!(loser_hp, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn simulation_tie_break_favours_higher_hp_share() {
+        let chart = neutral_chart();
+        // Zero attack deals 1 damage per hit, so nobody faints in time.
+        // Player 1 keeps ~99.5% of its HP, player 2 only ~95%
+        let tank = combatant(10_000, 0, 0, 50);
+        let small = combatant(1_000, 0, 0, 40);
+
+        for n in 0..50 {
+            let outcome = simulate_battle(&tank, &small, &chart, &seed(n));
+            assert_eq!(outcome.rounds, MAX_BATTLE_ROUNDS);
+            assert!(outcome.winner_is_player1);
+
+            let swapped = simulate_battle(&small, &tank, &chart, &seed(n));
+            assert_eq!(swapped.rounds, MAX_BATTLE_ROUNDS);
+            assert!(!swapped.winner_is_player1);
+        }
+    }
+
+    #[test]
+    fn fainted_side_does_not_act() {
+        let chart = neutral_chart();
+        // Player 2 is faster and one-shots player 1 unless dodged
+        let player1 = combatant(1, u16::MAX, 0, 10);
+        let player2 = combatant(500, u16::MAX, u16::MAX, 90);
+
+        let mut knocked_out = 0;
+        for n in 0..200 {
+            let hp = resolve_turn(&player1, &player2, [None, None], [1, 500], &chart, &seed(n));
+            if hp[0] == 0 {
+                assert_eq!(hp[1], 500, "seed {n}");
+                knocked_out += 1;
+            }
+        }
+        assert!(knocked_out > 0);
+    }
+
+    #[test]
+    fn action_bytes_distinguish_move_slots() {
+        assert_ne!(BattleAction::Attack.to_bytes(), BattleAction::UseMove { slot: 0 }.to_bytes());
+        assert_ne!(
+            BattleAction::UseMove { slot: 0 }.to_bytes(),
+            BattleAction::UseMove { slot: 1 }.to_bytes()
+        );
+        assert_ne!(
+            BattleAction::UseMove { slot: 1 }.to_bytes(),
+            BattleAction::UseMove { slot: 3 }.to_bytes()
+        );
+    }
+
+    #[test]
+    fn commitment_only_opens_with_the_same_inputs() {
+        let battle = Pubkey::new_from_array([1; 32]);
+        let action = BattleAction::UseMove { slot: 2 };
+        let salt = [7; 32];
+        let commitment = action_commitment(&battle, 3, action, &salt);
+
+        assert_eq!(commitment, action_commitment(&battle, 3, action, &salt));
+        assert_ne!(commitment, action_commitment(&battle, 4, action, &salt));
+        assert_ne!(
+            commitment,
+            action_commitment(&Pubkey::new_from_array([2; 32]), 3, action, &salt)
+        );
+        assert_ne!(commitment, action_commitment(&battle, 3, BattleAction::Attack, &salt));
+        assert_ne!(
+            commitment,
+            action_commitment(&battle, 3, BattleAction::UseMove { slot: 1 }, &salt)
+        );
+        assert_ne!(commitment, action_commitment(&battle, 3, action, &[8; 32]));
+    }
+
+    #[test]
+    fn damage_is_zero_only_on_a_dodge() {
+        let chart = neutral_chart();
+        // Same speed, so the plain dodge chance applies
+        let attacker = combatant(100, 1, 10, 50);
+        let defender = combatant(100, 1, u16::MAX, 50);
+
+        for n in 0..1_000 {
+            let dodged = BattleRng::new(&seed(n)).roll(100) < DODGE_CHANCE_PERCENT;
+            let damage = compute_damage(&attacker, &defender, &chart, &mut BattleRng::new(&seed(n)));
+
+            assert_eq!(damage == 0, dodged, "seed {n}");
+        }
+    }
+
+    #[test]
+    fn zero_attack_still_deals_damage() {
+        let chart = neutral_chart();
+        let attacker = combatant(100, 0, 0, 50);
+        let defender = combatant(100, 0, 0, 10);
+
+        // Defender is slower, so only the base dodge chance applies
+        let landed = (0..1_000)
+            .filter(|&n| compute_damage(&attacker, &defender, &chart, &mut BattleRng::new(&seed(n))) > 0)
+            .count();
+        let dodged = (0..1_000)
+            .filter(|&n| BattleRng::new(&seed(n)).roll(100) < DODGE_CHANCE_PERCENT)
+            .count();
+
+        assert_eq!(landed + dodged, 1_000);
+    }
+
+    #[test]
+    fn turn_seed_depends_on_both_salts() {
+        let battle_seed = seed(1);
+        let base = turn_seed(&battle_seed, 0, [&[1; 32], &[2; 32]]);
+
+        assert_eq!(base, turn_seed(&battle_seed, 0, [&[1; 32], &[2; 32]]));
+        assert_ne!(base, turn_seed(&battle_seed, 1, [&[1; 32], &[2; 32]]));
+        assert_ne!(base, turn_seed(&battle_seed, 0, [&[3; 32], &[2; 32]]));
+        assert_ne!(base, turn_seed(&battle_seed, 0, [&[1; 32], &[3; 32]]));
+        assert_ne!(base, turn_seed(&battle_seed, 0, [&[2; 32], &[1; 32]]));
+    }
+}
@@ -70,4 +70,172 @@ pub enum GameError {
 
     #[msg("Invalid metadata account")]
     InvalidMetadataAccount,
+
+    #[msg("Resolver is already registered")]
+    ResolverAlreadyRegistered,
+
+    #[msg("Resolver not found in registry")]
+    ResolverNotFound,
+
+    #[msg("Resolver registry is full")]
+    ResolverRegistryFull,
+
+    #[msg("Unauthorized: Signer is not a registered resolver")]
+    UnauthorizedResolver,
+
+    #[msg("Battle has not reached its timeout yet")]
+    BattleNotExpired,
+
+    #[msg("Invalid timeout: Must be greater than 0")]
+    InvalidTimeout,
+
+    #[msg("Stake amount is outside the platform limits")]
+    InvalidStakeAmount,
+
+    #[msg("Invalid stake limits: Minimum must be greater than 0 and not exceed maximum")]
+    InvalidStakeLimits,
+
+    #[msg("Token account does not hold this Pokémon")]
+    InvalidTokenHolder,
+
+    #[msg("Invalid evolution stage: Must be greater than 0")]
+    InvalidEvolutionStage,
+
+    #[msg("Collection already exists")]
+    CollectionAlreadyExists,
+
+    #[msg("Genetix collection has not been created")]
+    CollectionNotSet,
+
+    #[msg("Invalid collection for this Pokémon template")]
+    InvalidCollection,
+
+    #[msg("Template has reached its maximum supply")]
+    MaxSupplyReached,
+
+    #[msg("Minting for this template has not started yet")]
+    MintNotStarted,
+
+    #[msg("Minting for this template has ended")]
+    MintEnded,
+
+    #[msg("Invalid supply cap: Must be greater than 0 and not below times minted")]
+    InvalidSupplyCap,
+
+    #[msg("Invalid mint window: Start must be before end")]
+    InvalidMintWindow,
+
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    #[msg("Role registry is full")]
+    RoleRegistryFull,
+
+    #[msg("Role is not assigned to this account")]
+    RoleNotAssigned,
+
+    #[msg("This feature is currently paused")]
+    Paused,
+
+    #[msg("Invalid revenue splits: Shares must be positive, unique and sum to 10000 basis points")]
+    InvalidRevenueSplits,
+
+    #[msg("Revenue split recipient accounts do not match the configured splits")]
+    InvalidSplitRecipient,
+
+    #[msg("Quantity must be greater than 0")]
+    InvalidQuantity,
+
+    #[msg("Daily Soul Stone purchase cap exceeded")]
+    DailyCapExceeded,
+
+    #[msg("Invalid bulk tiers: Quantities must be ascending and discounts below 10000 basis points")]
+    InvalidBulkTiers,
+
+    #[msg("Soul Stones cannot be bought with MON")]
+    MonPriceNotSet,
+
+    #[msg("Pokemon level is too low for this evolution")]
+    LevelTooLow,
+
+    #[msg("Invalid level")]
+    InvalidLevel,
+
+    #[msg("Invalid element types: Secondary type must differ from the primary type")]
+    InvalidElementTypes,
+
+    #[msg("Type multiplier exceeds the maximum")]
+    InvalidTypeMultiplier,
+
+    #[msg("Invalid move: Power, accuracy (1-100) and PP must be set")]
+    InvalidMove,
+
+    #[msg("This Pokemon's template cannot learn this move")]
+    MoveNotLearnable,
+
+    #[msg("Pokemon already knows this move")]
+    MoveAlreadyKnown,
+
+    #[msg("Invalid move slot")]
+    InvalidMoveSlot,
+
+    #[msg("Action not available in this battle mode")]
+    InvalidBattleMode,
+
+    #[msg("No PP left for this move")]
+    NoPpRemaining,
+
+    #[msg("Turn already committed")]
+    AlreadyCommitted,
+
+    #[msg("Both players must commit before revealing")]
+    CommitmentsPending,
+
+    #[msg("Turn already revealed")]
+    AlreadyRevealed,
+
+    #[msg("Revealed action does not match the commitment")]
+    InvalidReveal,
+
+    #[msg("Turn deadline has not passed yet")]
+    TurnDeadlineNotReached,
+
+    #[msg("Opponent has not missed a commit or reveal")]
+    CannotClaimForfeit,
+
+    #[msg("Randomness account is not owned by the configured oracle")]
+    InvalidRandomnessAccount,
+
+    #[msg("Randomness has not been revealed yet")]
+    RandomnessNotReady,
+
+    #[msg("Randomness was already revealed")]
+    RandomnessAlreadyRevealed,
+
+    #[msg("Invalid challenge terms")]
+    InvalidChallengeTerms,
+
+    #[msg("Only the invited opponent can join this battle")]
+    NotInvitedOpponent,
+
+    #[msg("Pokemon does not meet the challenge requirements")]
+    ChallengeRequirementsNotMet,
+
+    #[msg("Pokemon is locked in an active battle")]
+    PokemonInBattle,
+
+    #[msg("Randomness oracle has not been set")]
+    RandomnessOracleNotSet,
+
+    #[msg("Invalid randomness oracle")]
+    InvalidRandomnessOracle,
+
+    #[msg("A turn is in progress, claim a forfeit instead")]
+    TurnInProgress,
+
+    #[msg("Turn deadline has passed")]
+    TurnDeadlinePassed,
+
+    #[msg("Randomness request is stale or was reopened")]
+    RandomnessRequestStale,
 }
\ No newline at end of file
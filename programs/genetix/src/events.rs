@@ -0,0 +1,368 @@
+use anchor_lang::prelude::*;
+use crate::state::{BattleAction, BattleMode, BulkDiscountTier, ChallengeTerms, ElementType, MonPaymentMode, RevenueSplit, Role};
+
+// ============ ADMIN EVENTS ============
+
+#[event]
+pub struct PlatformInitialized {
+    pub admin: Pubkey,
+    pub platform_fee_percentage: u16,
+}
+
+#[event]
+pub struct MonTokenCreated {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct SoulStoneTokenCreated {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct CollectionCreated {
+    pub collection_mint: Pubkey,
+    pub template_id: Option<u64>, // None for the Genetix collection
+}
+
+#[event]
+pub struct SoulStonePriceUpdated {
+    pub price_in_lamports: u64,
+}
+
+#[event]
+pub struct SoulStoneBulkPricingUpdated {
+    pub bulk_tiers: Vec<BulkDiscountTier>,
+    pub daily_cap_per_wallet: u64,
+}
+
+#[event]
+pub struct SoulStoneMonPriceUpdated {
+    pub price_in_mon: Option<u64>,
+    pub mon_payment_mode: MonPaymentMode,
+}
+
+#[event]
+pub struct TemplateAdded {
+    pub template_id: u64,
+    pub name: String,
+    pub price_in_mon: u64,
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+}
+
+#[event]
+pub struct TemplateUpdated {
+    pub template_id: u64,
+    pub name: String,
+    pub price_in_mon: u64,
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+}
+
+#[event]
+pub struct TemplateActiveSet {
+    pub template_id: u64,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct TemplateMintLimitsSet {
+    pub template_id: u64,
+    pub max_supply: Option<u64>,
+    pub mint_start_ts: Option<i64>,
+    pub mint_end_ts: Option<i64>,
+}
+
+#[event]
+pub struct TemplateTypesSet {
+    pub template_id: u64,
+    pub primary_type: ElementType,
+    pub secondary_type: Option<ElementType>,
+}
+
+#[event]
+pub struct TypeChartInitialized {
+    pub type_chart: Pubkey,
+}
+
+#[event]
+pub struct TypeEffectivenessSet {
+    pub attacking: ElementType,
+    pub defending: ElementType,
+    pub multiplier_bps: u16,
+}
+
+#[event]
+pub struct EvolutionRuleAdded {
+    pub template_id: u64,
+    pub evolution_stage: u8,
+    pub soul_stone_cost: u64,
+    pub min_level: u8,
+}
+
+#[event]
+pub struct MoveAdded {
+    pub move_id: u64,
+    pub name: String,
+    pub power: u16,
+    pub accuracy: u8,
+    pub element_type: ElementType,
+    pub pp: u8,
+    pub min_level: u8,
+    pub template_id: Option<u64>,
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub old_fee_percentage: u16,
+    pub new_fee_percentage: u16,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub proposed_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+#[event]
+pub struct PauseFlagsUpdated {
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct BattleTimeoutUpdated {
+    pub battle_timeout_seconds: i64,
+}
+
+#[event]
+pub struct RevealTimeoutUpdated {
+    pub reveal_timeout_seconds: i64,
+}
+
+#[event]
+pub struct RandomnessOracleUpdated {
+    pub randomness_oracle: Pubkey,
+}
+
+#[cfg(feature = "localnet")]
+#[event]
+pub struct LocalRandomnessRequested {
+    pub randomness: Pubkey,
+    pub randomness_id: u64,
+}
+
+#[cfg(feature = "localnet")]
+#[event]
+pub struct LocalRandomnessFulfilled {
+    pub randomness: Pubkey,
+    pub randomness_id: u64,
+}
+
+#[event]
+pub struct BattleStakeLimitsUpdated {
+    pub min_battle_stake: u64,
+    pub max_battle_stake: u64,
+}
+
+#[event]
+pub struct ResolverAdded {
+    pub resolver: Pubkey,
+}
+
+#[event]
+pub struct ResolverRemoved {
+    pub resolver: Pubkey,
+}
+
+#[event]
+pub struct RewardIssued {
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub admin: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RevenueSplitsUpdated {
+    pub revenue_splits: Vec<RevenueSplit>,
+}
+
+#[event]
+pub struct SolWithdrawn {
+    pub admin: Pubkey,
+    pub amount: u64,
+}
+
+// ============ POKEMON EVENTS ============
+
+#[event]
+pub struct PokemonMinted {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub template_id: u64,
+    pub price_in_mon: u64,
+}
+
+#[event]
+pub struct SoulStoneMinted {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub price_in_lamports: u64,
+}
+
+#[event]
+pub struct SoulStoneMintedWithMon {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub price_in_mon: u64,
+    pub mon_payment_mode: MonPaymentMode,
+}
+
+#[event]
+pub struct PokemonEvolved {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub evolution_stage: u8,
+    pub soul_stones_burned: u64,
+}
+
+#[event]
+pub struct PokemonLeveledUp {
+    pub mint: Pubkey,
+    pub level: u8,
+    pub experience: u64,
+}
+
+#[event]
+pub struct MoveLearned {
+    pub mint: Pubkey,
+    pub move_id: u64,
+    pub slot: u8,
+    pub replaced_move_id: Option<u64>,
+}
+
+#[event]
+pub struct PokemonMetadataUpdated {
+    pub mint: Pubkey,
+    pub uri: String,
+}
+
+#[event]
+pub struct PokemonOwnerSynced {
+    pub mint: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+// ============ BATTLE EVENTS ============
+
+#[event]
+pub struct BattleCreated {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub player1: Pubkey,
+    pub pokemon_mint: Pubkey,
+    pub stake_amount: u64,
+    pub mode: BattleMode,
+    pub terms: ChallengeTerms,
+}
+
+#[event]
+pub struct BattleJoined {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub player2: Pubkey,
+    pub pokemon_mint: Pubkey,
+}
+
+#[event]
+pub struct TurnCommitted {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub player: Pubkey,
+    pub turn: u16,
+}
+
+#[event]
+pub struct TurnRevealed {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub player: Pubkey,
+    pub turn: u16,
+    pub action: BattleAction,
+}
+
+#[event]
+pub struct TurnResolved {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub turn: u16,
+    pub player1_action: BattleAction,
+    pub player2_action: BattleAction,
+    pub player1_hp: u16,
+    pub player2_hp: u16,
+}
+
+#[event]
+pub struct BattleForfeited {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+}
+
+#[event]
+pub struct BattleResolved {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+    pub winner_amount: u64,
+    pub platform_fee: u64,
+    pub rounds: u8,
+    pub seed: [u8; 32], // Committed battle seed
+    pub randomness: Option<[u8; 32]>, // Oracle value mixed into the seed, if any
+}
+
+#[event]
+pub struct BattleCancelled {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub refund_amount: u64,
+}
+
+#[event]
+pub struct BattleExpired {
+    pub battle: Pubkey,
+    pub battle_id: u64,
+    pub player1_refund: u64,
+    pub player2_refund: u64,
+}
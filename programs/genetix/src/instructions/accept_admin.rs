@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::AdminTransferred};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.pending_admin == Some(new_admin.key()) @ GameError::NotPendingAdmin
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let previous_admin = platform.admin;
+
+    platform.admin = ctx.accounts.new_admin.key();
+    platform.pending_admin = None;
+
+    msg!("Admin changed from {} to {}", previous_admin, platform.admin);
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: platform.admin,
+    });
+
+    Ok(())
+}
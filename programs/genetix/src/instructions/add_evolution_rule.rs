@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::EvolutionRuleAdded, leveling::MAX_LEVEL, ANCHOR_DISCRIMINATOR};
+
+#[derive(Accounts)]
+#[instruction(template_id: u64, evolution_stage: u8)]
+pub struct AddEvolutionRule<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = pokemon_template.bump
+    )]
+    pub pokemon_template: Account<'info, PokemonTemplate>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + EvolutionRule::INIT_SPACE,
+        seeds = [
+            b"evolution_rule",
+            template_id.to_le_bytes().as_ref(),
+            evolution_stage.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub evolution_rule: Account<'info, EvolutionRule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_evolution_rule(
+    ctx: Context<AddEvolutionRule>,
+    template_id: u64,
+    evolution_stage: u8,
+    metadata_uri: String,
+    stats: BaseStats,
+    soul_stone_cost: u64,
+    min_level: u8,
+) -> Result<()> {
+    let BaseStats { hp, attack, defense, speed } = stats;
+    require!(evolution_stage > 0, GameError::InvalidEvolutionStage);
+    require!(metadata_uri.len() <= 200, GameError::UriTooLong);
+    require!(!metadata_uri.is_empty(), GameError::InvalidMetadataUri);
+    require!(soul_stone_cost > 0, GameError::InvalidPrice);
+    require!(min_level <= MAX_LEVEL, GameError::InvalidLevel);
+    require!(
+        hp > 0 && attack > 0 && defense > 0 && speed > 0,
+        GameError::InvalidStats
+    );
+
+    let rule = &mut ctx.accounts.evolution_rule;
+    rule.template_id = template_id;
+    rule.evolution_stage = evolution_stage;
+    rule.metadata_uri = metadata_uri;
+    rule.hp = hp;
+    rule.attack = attack;
+    rule.defense = defense;
+    rule.speed = speed;
+    rule.soul_stone_cost = soul_stone_cost;
+    rule.min_level = min_level;
+    rule.bump = ctx.bumps.evolution_rule;
+
+    msg!(
+        "Evolution rule added: template {} stage {} costs {} Soul Stone(s)",
+        template_id,
+        evolution_stage,
+        soul_stone_cost
+    );
+
+    emit!(EvolutionRuleAdded {
+        template_id,
+        evolution_stage,
+        soul_stone_cost,
+        min_level,
+    });
+
+    Ok(())
+}
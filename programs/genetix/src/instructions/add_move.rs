@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::MoveAdded, leveling::MAX_LEVEL, ANCHOR_DISCRIMINATOR};
+
+#[derive(Accounts)]
+#[instruction(move_id: u64)]
+pub struct AddMove<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Move::INIT_SPACE,
+        seeds = [b"move", move_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub move_account: Account<'info, Move>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_move(
+    ctx: Context<AddMove>,
+    move_id: u64,
+    name: String,
+    stats: MoveStats,
+    min_level: u8,
+    template_id: Option<u64>,
+) -> Result<()> {
+    let MoveStats { power, accuracy, element_type, pp } = stats;
+    require!(name.len() <= 32, GameError::NameTooLong);
+    require!(
+        power > 0 && (1..=100).contains(&accuracy) && pp > 0,
+        GameError::InvalidMove
+    );
+    require!(min_level <= MAX_LEVEL, GameError::InvalidLevel);
+
+    let move_account = &mut ctx.accounts.move_account;
+    move_account.move_id = move_id;
+    move_account.name = name;
+    move_account.power = power;
+    move_account.accuracy = accuracy;
+    move_account.element_type = element_type;
+    move_account.pp = pp;
+    move_account.min_level = min_level;
+    move_account.template_id = template_id;
+    move_account.bump = ctx.bumps.move_account;
+
+    msg!("Move added: ID {}, Name: {}", move_id, move_account.name);
+
+    emit!(MoveAdded {
+        move_id,
+        name: move_account.name.clone(),
+        power,
+        accuracy,
+        element_type,
+        pp,
+        min_level,
+        template_id,
+    });
+
+    Ok(())
+}
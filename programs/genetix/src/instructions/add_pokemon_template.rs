@@ -1,22 +1,32 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::GameError, ANCHOR_DISCRIMINATOR};
+use crate::{state::*, errors::GameError, events::TemplateAdded, ANCHOR_DISCRIMINATOR};
 
 #[derive(Accounts)]
 #[instruction(template_id: u64)]
 pub struct AddPokemonTemplate<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>, // Admin or template curator
 
     #[account(
         seeds = [b"platform"],
         bump = platform.bump,
-        has_one = admin @ GameError::Unauthorized
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
     )]
     pub platform: Account<'info, Platform>,
 
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         space = ANCHOR_DISCRIMINATOR + PokemonTemplate::INIT_SPACE,
         seeds = [b"template", template_id.to_le_bytes().as_ref()],
         bump
@@ -32,11 +42,9 @@ pub fn add_pokemon_template(
     name: String,
     base_uri: String,
     price_in_mon: u64,
-    hp: u16,
-    attack: u16,
-    defense: u16,
-    speed: u16,
+    stats: BaseStats,
 ) -> Result<()> {
+    let BaseStats { hp, attack, defense, speed } = stats;
     require!(name.len() <= 32, GameError::NameTooLong);
     require!(base_uri.len() <= 200, GameError::UriTooLong);
     require!(price_in_mon > 0, GameError::InvalidPrice);
@@ -56,9 +64,25 @@ pub fn add_pokemon_template(
     template.speed = speed;
     template.is_active = true;
     template.times_minted = 0;
+    template.max_supply = None;
+    template.mint_start_ts = None;
+    template.mint_end_ts = None;
+    template.collection_mint = None;
+    template.primary_type = ElementType::Normal;
+    template.secondary_type = None;
     template.bump = ctx.bumps.pokemon_template;
 
     msg!("Pokémon template added: ID {}, Name: {}", template_id, template.name);
 
+    emit!(TemplateAdded {
+        template_id,
+        name: template.name.clone(),
+        price_in_mon,
+        hp,
+        attack,
+        defense,
+        speed,
+    });
+
     Ok(())
 }
\ No newline at end of file
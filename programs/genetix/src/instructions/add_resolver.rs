@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::ResolverAdded, ANCHOR_DISCRIMINATOR, MAX_RESOLVERS};
+
+#[derive(Accounts)]
+pub struct AddResolver<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + ResolverRegistry::INIT_SPACE,
+        seeds = [b"resolver_registry"],
+        bump
+    )]
+    pub resolver_registry: Account<'info, ResolverRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_resolver(
+    ctx: Context<AddResolver>,
+    resolver: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.resolver_registry;
+
+    require!(
+        !registry.resolvers.contains(&resolver),
+        GameError::ResolverAlreadyRegistered
+    );
+    require!(
+        registry.resolvers.len() < MAX_RESOLVERS,
+        GameError::ResolverRegistryFull
+    );
+
+    registry.resolvers.push(resolver);
+    registry.bump = ctx.bumps.resolver_registry;
+
+    msg!("Resolver registered: {}", resolver);
+
+    emit!(ResolverAdded { resolver });
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{transfer_checked, close_account, Mint, Token, TokenAccount, TransferChecked, CloseAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::BattleCancelled,
+    pokemon_lock::{self, PokemonLock},
+};
+
+#[derive(Accounts)]
+pub struct CancelBattle<'info> {
+    #[account(mut)]
+    pub player1: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        close = player1,
+        seeds = [b"battle", battle.battle_id.to_le_bytes().as_ref()],
+        bump = battle.bump,
+        has_one = player1 @ GameError::NotBattleParticipant
+    )]
+    pub battle: Account<'info, Battle>,
+
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
+    pub mon_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = battle
+    )]
+    pub battle_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = player1,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player1
+    )]
+    pub player1_mon_account: Account<'info, TokenAccount>,
+
+    // Pokemon data, released from the battle lock
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player1_pokemon.as_ref()],
+        bump = player1_pokemon_data.bump
+    )]
+    pub player1_pokemon_data: Account<'info, PokemonData>,
+
+    // Player 1's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 1's Pokemon mint, must match the battle record
+    #[account(
+        address = battle.player1_pokemon @ GameError::InvalidPokemonMint
+    )]
+    pub player1_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player1_pokemon_mint,
+        token::authority = player1
+    )]
+    pub player1_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 1's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player1_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player1_pokemon_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_battle(ctx: Context<CancelBattle>) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+
+    require!(
+        battle.status == BattleStatus::WaitingForPlayer2,
+        GameError::InvalidBattleStatus
+    );
+
+    let refund_amount = ctx.accounts.battle_escrow.amount;
+
+    let binding = battle.battle_id.to_le_bytes();
+    let battle_seeds = &[
+        b"battle".as_ref(),
+        binding.as_ref(),
+        &[battle.bump],
+    ];
+    let signer_seeds = &[&battle_seeds[..]];
+
+    // Return stake to player 1
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.battle_escrow.to_account_info(),
+                mint: ctx.accounts.mon_token_mint.to_account_info(),
+                to: ctx.accounts.player1_mon_account.to_account_info(),
+                authority: ctx.accounts.battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        refund_amount,
+        ctx.accounts.mon_token_mint.decimals,
+    )?;
+
+    msg!("Refunded {} MON tokens to player 1", refund_amount);
+
+    // Close escrow account
+    close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.battle_escrow.to_account_info(),
+                destination: ctx.accounts.player1.to_account_info(),
+                authority: ctx.accounts.battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+    )?;
+
+    // Release player 1's Pokemon
+    pokemon_lock::unlock_pokemon(
+        PokemonLock {
+            platform: &ctx.accounts.platform,
+            token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+            mint: ctx.accounts.player1_pokemon_mint.to_account_info(),
+            edition: ctx.accounts.player1_pokemon_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        &mut ctx.accounts.player1_pokemon_data,
+    )?;
+
+    let battle = &mut ctx.accounts.battle;
+    battle.status = BattleStatus::Cancelled;
+    battle.resolved_at = Some(Clock::get()?.unix_timestamp);
+
+    msg!("Battle {} cancelled", battle.battle_id);
+
+    emit!(BattleCancelled {
+        battle: battle.key(),
+        battle_id: battle.battle_id,
+        refund_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::{BattleForfeited, BattleResolved},
+    settlement::{self, SettleBattle}, pokemon_lock::PokemonLock, PAUSE_BATTLES,
+};
+
+#[derive(Accounts)]
+pub struct ClaimForfeit<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>, // Participant whose opponent missed the deadline
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, PlatformTreasury>>,
+
+    #[account(
+        mut,
+        seeds = [b"battle", battle.battle_id.to_le_bytes().as_ref()],
+        bump = battle.bump
+    )]
+    pub battle: Box<Account<'info, Battle>>,
+
+    /// CHECK: Player 1 address, must match the battle record
+    #[account(
+        mut,
+        address = battle.player1 @ GameError::NotBattleParticipant
+    )]
+    pub player1: UncheckedAccount<'info>,
+
+    /// CHECK: Player 2 address, must match the battle record
+    #[account(
+        mut,
+        constraint = battle.player2 == Some(player2.key()) @ GameError::NotBattleParticipant
+    )]
+    pub player2: UncheckedAccount<'info>,
+
+    // MON token accounts
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
+    pub mon_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = battle
+    )]
+    pub battle_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player1
+    )]
+    pub player1_mon_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player2
+    )]
+    pub player2_mon_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = treasury.mon_token_vault
+    )]
+    pub platform_fee_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player1_pokemon.as_ref()],
+        bump = player1_pokemon_data.bump
+    )]
+    pub player1_pokemon_data: Box<Account<'info, PokemonData>>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player2_pokemon.unwrap().as_ref()],
+        bump = player2_pokemon_data.bump
+    )]
+    pub player2_pokemon_data: Box<Account<'info, PokemonData>>,
+
+    // Player 1's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 1's Pokemon mint, must match the battle record
+    #[account(
+        address = battle.player1_pokemon @ GameError::InvalidPokemonMint
+    )]
+    pub player1_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player1_pokemon_mint,
+        token::authority = player1
+    )]
+    pub player1_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 1's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player1_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player1_pokemon_edition: UncheckedAccount<'info>,
+
+    // Player 2's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 2's Pokemon mint, must match the battle record
+    #[account(
+        constraint = battle.player2_pokemon == Some(player2_pokemon_mint.key()) @ GameError::InvalidPokemonMint
+    )]
+    pub player2_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player2_pokemon_mint,
+        token::authority = player2
+    )]
+    pub player2_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 2's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player2_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player2_pokemon_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_forfeit(ctx: Context<ClaimForfeit>) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+
+    require!(
+        battle.status == BattleStatus::InProgress,
+        GameError::InvalidBattleStatus
+    );
+    require!(
+        battle.mode == BattleMode::TurnBased,
+        GameError::InvalidBattleMode
+    );
+
+    let player = ctx.accounts.player.key();
+    let is_player1 = player == battle.player1;
+    require!(
+        is_player1 || battle.player2 == Some(player),
+        GameError::NotBattleParticipant
+    );
+
+    require!(
+        battle.randomness.is_some(),
+        GameError::RandomnessNotReady
+    );
+
+    // Time spent paused does not count against the opponent
+    let deadline = battle
+        .effective_turn_deadline(&ctx.accounts.platform)
+        .ok_or(GameError::CannotClaimForfeit)?;
+    require!(
+        Clock::get()?.unix_timestamp > deadline,
+        GameError::TurnDeadlineNotReached
+    );
+
+    // Claimant must have done their part of the current phase while the
+    // opponent has not: committed vs. not committed, or revealed vs. not
+    let (own_commitment, opponent_commitment, own_action, opponent_action) = if is_player1 {
+        (battle.player1_commitment, battle.player2_commitment, battle.player1_action, battle.player2_action)
+    } else {
+        (battle.player2_commitment, battle.player1_commitment, battle.player2_action, battle.player1_action)
+    };
+    let opponent_missed = if own_commitment.is_some() && opponent_commitment.is_some() {
+        own_action.is_some() && opponent_action.is_none()
+    } else {
+        own_commitment.is_some() && opponent_commitment.is_none()
+    };
+    require!(opponent_missed, GameError::CannotClaimForfeit);
+
+    msg!("Battle {} forfeited, {} wins", battle.battle_id, player);
+
+    let rounds = u8::try_from(battle.turn).unwrap_or(u8::MAX);
+    let settlement = settlement::settle_battle(
+        SettleBattle {
+            battle: &mut ctx.accounts.battle,
+            treasury: &mut ctx.accounts.treasury,
+            battle_escrow: &ctx.accounts.battle_escrow,
+            mon_token_mint: &ctx.accounts.mon_token_mint,
+            platform_fee_account: &ctx.accounts.platform_fee_account,
+            player1_mon_account: &ctx.accounts.player1_mon_account,
+            player2_mon_account: &ctx.accounts.player2_mon_account,
+            player1_pokemon_data: &mut ctx.accounts.player1_pokemon_data,
+            player2_pokemon_data: &mut ctx.accounts.player2_pokemon_data,
+            player1_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player1_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player1_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            },
+            player2_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player2_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player2_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player2_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            },
+            escrow_rent_destination: ctx.accounts.player1.to_account_info(),
+            token_program: &ctx.accounts.token_program,
+        },
+        is_player1,
+    )?;
+
+    emit!(BattleForfeited {
+        battle: ctx.accounts.battle.key(),
+        battle_id: ctx.accounts.battle.battle_id,
+        winner: settlement.winner,
+        loser: settlement.loser,
+    });
+
+    emit!(BattleResolved {
+        battle: ctx.accounts.battle.key(),
+        battle_id: ctx.accounts.battle.battle_id,
+        winner: settlement.winner,
+        loser: settlement.loser,
+        winner_amount: settlement.winner_amount,
+        platform_fee: settlement.platform_fee,
+        rounds,
+        seed: ctx.accounts.battle.seed,
+        randomness: ctx.accounts.battle.randomness,
+    });
+
+    // Battle rent goes back to player 1, who paid for it
+    ctx.accounts.battle.close(ctx.accounts.player1.to_account_info())?;
+
+    Ok(())
+}
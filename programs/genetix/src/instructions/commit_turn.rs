@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TurnCommitted, randomness, PAUSE_BATTLES};
+
+#[derive(Accounts)]
+pub struct CommitTurn<'info> {
+    pub player: Signer<'info>, // Either participant
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"battle", battle.battle_id.to_le_bytes().as_ref()],
+        bump = battle.bump
+    )]
+    pub battle: Account<'info, Battle>,
+
+    /// CHECK: Randomness account committed at join, validated in the handler
+    #[account(
+        constraint = battle.randomness_account == Some(randomness_account.key()) @ GameError::InvalidRandomnessAccount
+    )]
+    pub randomness_account: UncheckedAccount<'info>,
+}
+
+/// Commit to `battle_engine::action_commitment(battle, turn, action, salt)`
+pub fn commit_turn(
+    ctx: Context<CommitTurn>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let battle = &mut ctx.accounts.battle;
+
+    require!(
+        battle.status == BattleStatus::InProgress,
+        GameError::InvalidBattleStatus
+    );
+    require!(
+        battle.mode == BattleMode::TurnBased,
+        GameError::InvalidBattleMode
+    );
+
+    // No turn starts before the oracle has answered, so a pending request
+    // can never stall a reveal into a forfeit. The value is kept on the battle
+    if battle.randomness.is_none() {
+        battle.randomness = Some(randomness::revealed_value(
+            &ctx.accounts.randomness_account,
+            &ctx.accounts.platform.randomness_oracle,
+            battle.randomness_request_slot,
+        )?);
+    }
+
+    // Committing after the opponent's deadline ran out would dodge their claim
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        battle
+            .effective_turn_deadline(&ctx.accounts.platform)
+            .is_none_or(|deadline| now <= deadline),
+        GameError::TurnDeadlinePassed
+    );
+
+    let player = ctx.accounts.player.key();
+    let slot = if player == battle.player1 {
+        &mut battle.player1_commitment
+    } else if battle.player2 == Some(player) {
+        &mut battle.player2_commitment
+    } else {
+        return err!(GameError::NotBattleParticipant);
+    };
+    require!(slot.is_none(), GameError::AlreadyCommitted);
+    *slot = Some(commitment);
+
+    // The deadline restarts so the opponent gets a full window to commit,
+    // then again so both get a full window to reveal
+    let deadline = now
+        .checked_add(ctx.accounts.platform.reveal_timeout_seconds)
+        .ok_or(GameError::MathOverflow)?;
+    battle.record_turn_activity(&ctx.accounts.platform, now);
+    battle.turn_deadline = Some(deadline);
+
+    msg!("Turn {} committed by {}", battle.turn, player);
+
+    emit!(TurnCommitted {
+        battle: battle.key(),
+        battle_id: battle.battle_id,
+        player,
+        turn: battle.turn,
+    });
+
+    Ok(())
+}
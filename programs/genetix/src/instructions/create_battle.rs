@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    metadata::Metadata,
     token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
-use crate::{state::*, errors::GameError, ANCHOR_DISCRIMINATOR};
-
-const BATTLE_STAKE_AMOUNT: u64 = 10_000_000_000; // 10 MON tokens (with 9 decimals)
+use crate::{
+    state::*, errors::GameError, events::BattleCreated, battle_engine, leveling::MAX_LEVEL,
+    pokemon_lock::{self, PokemonLock}, ANCHOR_DISCRIMINATOR, PAUSE_BATTLES,
+};
 
 #[derive(Accounts)]
 #[instruction(battle_id: u64)]
@@ -16,7 +18,8 @@ pub struct CreateBattle<'info> {
     #[account(
         mut,
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -51,18 +54,41 @@ pub struct CreateBattle<'info> {
     )]
     pub battle_escrow: Account<'info, TokenAccount>,
 
-    // Pokemon verification
-#[account(
-    seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
-    bump = pokemon_data.bump
-)]
-pub pokemon_data: Account<'info, PokemonData>,
-
+    // Pokemon verification, locked into this battle until it ends
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
+        bump = pokemon_data.bump
+    )]
+    pub pokemon_data: Account<'info, PokemonData>,
 
     /// CHECK: Pokemon mint address
     pub pokemon_mint: UncheckedAccount<'info>,
 
+    // Ownership is proven by holding the NFT, not by PokemonData.owner
+    #[account(
+        mut,
+        token::mint = pokemon_mint,
+        token::authority = player1,
+        constraint = player1_pokemon_account.amount == 1 @ GameError::NotPokemonOwner
+    )]
+    pub player1_pokemon_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub pokemon_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -71,22 +97,33 @@ pub fn create_battle(
     ctx: Context<CreateBattle>,
     battle_id: u64,
     pokemon_mint: Pubkey,
+    stake_amount: u64,
+    mode: BattleMode,
+    terms: ChallengeTerms,
 ) -> Result<()> {
+    let platform = &ctx.accounts.platform;
+    require!(
+        stake_amount >= platform.min_battle_stake && stake_amount <= platform.max_battle_stake,
+        GameError::InvalidStakeAmount
+    );
     require!(
-        ctx.accounts.player1_mon_account.amount >= BATTLE_STAKE_AMOUNT,
+        ctx.accounts.player1_mon_account.amount >= stake_amount,
         GameError::InsufficientMonTokens
     );
-require_keys_eq!(
-    ctx.accounts.pokemon_data.owner,
-    ctx.accounts.player1.key(),
-    GameError::NotPokemonOwner
-);
-
-
+    require_keys_eq!(
+        ctx.accounts.pokemon_mint.key(),
+        pokemon_mint,
+        GameError::InvalidPokemonMint
+    );
+    require!(
+        terms.invited_opponent != Some(ctx.accounts.player1.key())
+            && terms.min_opponent_level.is_none_or(|level| level <= MAX_LEVEL),
+        GameError::InvalidChallengeTerms
+    );
 
     // Calculate platform fee
     let platform_fee_bps = ctx.accounts.platform.platform_fee_percentage;
-    let platform_fee = (BATTLE_STAKE_AMOUNT as u128)
+    let platform_fee = (stake_amount as u128)
         .checked_mul(platform_fee_bps as u128)
         .and_then(|x| x.checked_div(10000))
         .and_then(|x| u64::try_from(x).ok())
@@ -102,24 +139,59 @@ require_keys_eq!(
                 authority: ctx.accounts.player1.to_account_info(),
             },
         ),
-        BATTLE_STAKE_AMOUNT,
+        stake_amount,
     )?;
 
-    msg!("Player 1 staked {} MON tokens", BATTLE_STAKE_AMOUNT);
+    msg!("Player 1 staked {} MON tokens", stake_amount);
+
+    // Lock the Pokemon into this battle and freeze its NFT
+    pokemon_lock::lock_pokemon(
+        PokemonLock {
+            platform: &ctx.accounts.platform,
+            token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+            mint: ctx.accounts.pokemon_mint.to_account_info(),
+            edition: ctx.accounts.pokemon_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        ctx.accounts.player1.to_account_info(),
+        &mut ctx.accounts.pokemon_data,
+        ctx.accounts.battle.key(),
+    )?;
 
     // Initialize battle
+    let clock = Clock::get()?;
     let battle = &mut ctx.accounts.battle;
     battle.battle_id = battle_id;
     battle.player1 = ctx.accounts.player1.key();
     battle.player2 = None;
     battle.player1_pokemon = pokemon_mint;
     battle.player2_pokemon = None;
-    battle.stake_amount = BATTLE_STAKE_AMOUNT;
+    battle.stake_amount = stake_amount;
     battle.platform_fee_amount = platform_fee;
     battle.status = BattleStatus::WaitingForPlayer2;
     battle.winner = None;
-    battle.created_at = Clock::get()?.unix_timestamp;
+    battle.created_at = clock.unix_timestamp;
+    battle.joined_at = None;
     battle.resolved_at = None;
+    battle.seed = battle_engine::commit_battle_seed(
+        &battle.key(),
+        &battle.player1,
+        &clock,
+    );
+    battle.mode = mode;
+    battle.terms = terms;
+    battle.player1_hp = 0;
+    battle.player2_hp = 0;
+    battle.turn = 0;
+    battle.player1_pp = [0; 4];
+    battle.player2_pp = [0; 4];
+    battle.reset_turn();
+    battle.last_turn_at = None;
+    battle.turn_pause_seconds = 0;
+    battle.randomness_account = None;
+    battle.randomness_request_slot = 0;
+    battle.randomness = None;
     battle.bump = ctx.bumps.battle;
 
     // Update platform counter
@@ -129,5 +201,15 @@ require_keys_eq!(
 
     msg!("Battle {} created, waiting for opponent", battle_id);
 
+    emit!(BattleCreated {
+        battle: ctx.accounts.battle.key(),
+        battle_id,
+        player1: ctx.accounts.player1.key(),
+        pokemon_mint,
+        stake_amount,
+        mode,
+        terms,
+    });
+
     Ok(())
 }
\ No newline at end of file
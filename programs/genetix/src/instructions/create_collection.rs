@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{
+        create_master_edition_v3, create_metadata_accounts_v3,
+        mpl_token_metadata::types::{CollectionDetails, DataV2},
+        CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+    },
+    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+};
+use crate::{state::Platform, errors::GameError, events::CollectionCreated};
+
+#[derive(Accounts)]
+pub struct CreateCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = 0,
+        mint::authority = platform,
+        mint::freeze_authority = platform,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = collection_mint,
+        associated_token::authority = platform
+    )]
+    pub platform_collection_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_collection(
+    ctx: Context<CreateCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    require!(
+        ctx.accounts.platform.collection_mint == Pubkey::default(),
+        GameError::CollectionAlreadyExists
+    );
+    require!(name.len() <= 32, GameError::NameTooLong);
+    require!(uri.len() <= 200, GameError::UriTooLong);
+
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[ctx.accounts.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
+    // Mint the collection NFT to the platform
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.platform_collection_account.to_account_info(),
+                authority: ctx.accounts.platform.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Create sized collection metadata
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: ctx.accounts.platform.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
+                payer: ctx.accounts.admin.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true, // Is mutable
+        true, // Update authority is signer
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+
+    // Create master edition
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.edition_account.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
+                mint_authority: ctx.accounts.platform.to_account_info(),
+                payer: ctx.accounts.admin.to_account_info(),
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0), // Max Supply
+    )?;
+
+    ctx.accounts.platform.collection_mint = ctx.accounts.collection_mint.key();
+
+    msg!("Genetix collection created: {}", ctx.accounts.collection_mint.key());
+
+    emit!(CollectionCreated {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        template_id: None,
+    });
+
+    Ok(())
+}
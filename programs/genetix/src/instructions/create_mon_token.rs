@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
+    associated_token::get_associated_token_address,
     metadata::{
         create_metadata_accounts_v3, mpl_token_metadata::types::DataV2,
         CreateMetadataAccountsV3, Metadata,
     },
     token::{Mint, Token},
 };
-use crate::{state::Platform, errors::GameError, MON_TOKEN_DECIMALS};
+use crate::{state::*, errors::GameError, events::MonTokenCreated, MON_TOKEN_DECIMALS};
 
 #[derive(Accounts)]
 pub struct CreateMonToken<'info> {
@@ -21,6 +22,13 @@ pub struct CreateMonToken<'info> {
     )]
     pub platform: Account<'info, Platform>,
 
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
     #[account(
         init,
         payer = admin,
@@ -86,7 +94,17 @@ pub fn create_mon_token(
     // Update platform with MON token mint
     ctx.accounts.platform.mon_token_mint = ctx.accounts.mon_token_mint.key();
 
+    // Platform fees and mint revenue accumulate in the platform's MON ATA
+    ctx.accounts.treasury.mon_token_vault = get_associated_token_address(
+        &ctx.accounts.platform.key(),
+        &ctx.accounts.mon_token_mint.key(),
+    );
+
     msg!("MON token created successfully: {}", ctx.accounts.mon_token_mint.key());
 
+    emit!(MonTokenCreated {
+        mint: ctx.accounts.mon_token_mint.key(),
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -6,7 +6,7 @@ use anchor_spl::{
     },
     token::{Mint, Token},
 };
-use crate::{state::*, errors::GameError, ANCHOR_DISCRIMINATOR, SOUL_STONE_DECIMALS};
+use crate::{state::*, errors::GameError, events::SoulStoneTokenCreated, ANCHOR_DISCRIMINATOR, SOUL_STONE_DECIMALS};
 
 #[derive(Accounts)]
 pub struct CreateSoulStoneToken<'info> {
@@ -100,9 +100,18 @@ pub fn create_soul_stone_token(
     config.mint = ctx.accounts.soul_stone_mint.key();
     config.price_in_lamports = 0;
     config.total_minted = 0;
+    config.bulk_tiers = Vec::new();
+    config.daily_cap_per_wallet = 0;
+    config.price_in_mon = None;
+    config.mon_payment_mode = MonPaymentMode::Burn;
+    config.total_mon_burned = 0;
     config.bump = ctx.bumps.soul_stone_config;
 
     msg!("Soul Stone token created successfully: {}", ctx.accounts.soul_stone_mint.key());
 
+    emit!(SoulStoneTokenCreated {
+        mint: ctx.accounts.soul_stone_mint.key(),
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -3,11 +3,11 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::{
         update_metadata_accounts_v2, mpl_token_metadata::types::DataV2,
-        UpdateMetadataAccountsV2, Metadata,
+        MetadataAccount, UpdateMetadataAccountsV2, Metadata,
     },
     token::{burn, Burn, Mint, Token, TokenAccount},
 };
-use crate::{state::*, errors::GameError};
+use crate::{state::*, errors::GameError, events::PokemonEvolved, leveling, PAUSE_EVOLUTION};
 
 #[derive(Accounts)]
 pub struct EvolvePokemon<'info> {
@@ -16,7 +16,8 @@ pub struct EvolvePokemon<'info> {
 
     #[account(
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_EVOLUTION) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -28,12 +29,33 @@ pub struct EvolvePokemon<'info> {
     #[account(
         mut,
         seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
-        bump = pokemon_data.bump
+        bump = pokemon_data.bump,
+        constraint = pokemon_data.active_battle.is_none() @ GameError::PokemonInBattle
     )]
     pub pokemon_data: Account<'info, PokemonData>,
 
-    /// CHECK: Metadata account for Pokemon NFT (validated at runtime)
-    pub metadata_account: UncheckedAccount<'info>,
+    /// Rule for the next evolution stage of this Pokemon's template
+    #[account(
+        seeds = [
+            b"evolution_rule",
+            pokemon_data.template_id.to_le_bytes().as_ref(),
+            pokemon_data.evolution_stage.saturating_add(1).to_le_bytes().as_ref()
+        ],
+        bump = evolution_rule.bump
+    )]
+    pub evolution_rule: Account<'info, EvolutionRule>,
+
+    /// Player's token account holding the Pokemon NFT
+    #[account(
+        token::mint = pokemon_mint,
+        token::authority = player,
+        constraint = player_pokemon_account.amount == 1 @ GameError::NotPokemonOwner
+    )]
+    pub player_pokemon_account: Account<'info, TokenAccount>,
+
+    /// Metadata account for Pokemon NFT (address validated at runtime)
+    #[account(mut)]
+    pub metadata_account: Box<Account<'info, MetadataAccount>>,
 
     // Soul Stone burn
     #[account(
@@ -55,33 +77,21 @@ pub struct EvolvePokemon<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn evolve_pokemon(
-    ctx: Context<EvolvePokemon>,
-    new_metadata_uri: String,
-    new_hp: u16,
-    new_attack: u16,
-    new_defense: u16,
-    new_speed: u16,
-) -> Result<()> {
+pub fn evolve_pokemon(ctx: Context<EvolvePokemon>) -> Result<()> {
+    let soul_stone_cost = ctx.accounts.evolution_rule.soul_stone_cost;
+
     // Basic validations
-    require!(new_metadata_uri.len() <= 200, GameError::UriTooLong);
     require!(
-        new_hp > 0 && new_attack > 0 && new_defense > 0 && new_speed > 0,
-        GameError::InvalidStats
-    );
-    require!(
-        ctx.accounts.player_soul_stone_account.amount >= 1,
+        ctx.accounts.player_soul_stone_account.amount >= soul_stone_cost,
         GameError::NoSoulStone
     );
-
-    // ----- Runtime ownership / consistency checks -----
-    // Ensure pokemon_data.owner == player
-    require_keys_eq!(
-        ctx.accounts.pokemon_data.owner,
-        ctx.accounts.player.key(),
-        GameError::NotPokemonOwner
+    require!(
+        ctx.accounts.pokemon_data.level >= ctx.accounts.evolution_rule.min_level,
+        GameError::LevelTooLow
     );
 
+    // ----- Runtime consistency checks -----
+    // Ownership is enforced by player_pokemon_account holding the NFT
     // Ensure pokemon_data.mint == provided pokemon_mint
     require_keys_eq!(
         ctx.accounts.pokemon_data.mint,
@@ -116,28 +126,36 @@ pub fn evolve_pokemon(
                 authority: ctx.accounts.player.to_account_info(),
             },
         ),
-        1,
+        soul_stone_cost,
     )?;
 
-    msg!("Burned 1 Soul Stone for evolution");
+    msg!("Burned {} Soul Stone(s) for evolution", soul_stone_cost);
 
     // ----- Update Metadata (CPI to token-metadata program) -----
+    // Platform PDA is the update authority, so it signs the metadata update
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[ctx.accounts.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
     update_metadata_accounts_v2(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_metadata_program.to_account_info(),
             UpdateMetadataAccountsV2 {
                 metadata: ctx.accounts.metadata_account.to_account_info(),
-                update_authority: ctx.accounts.player.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
             },
+            signer_seeds,
         ),
         None, // new_update_authority
         Some(DataV2 {
             name: ctx.accounts.pokemon_data.name.clone(),
             symbol: "PKMN".to_string(),
-            uri: new_metadata_uri.clone(),
+            uri: ctx.accounts.evolution_rule.metadata_uri.clone(),
             seller_fee_basis_points: 0,
             creators: None,
-            collection: None,
+            collection: ctx.accounts.metadata_account.collection.clone(), // Keep verified collection
             uses: None,
         }),
         None, // primary_sale_happened
@@ -147,25 +165,29 @@ pub fn evolve_pokemon(
     msg!("Updated Pokemon metadata URI");
 
     // ----- Update On-chain PokemonData -----
+    let rule = &ctx.accounts.evolution_rule;
     let pokemon_data = &mut ctx.accounts.pokemon_data;
-    pokemon_data.current_metadata_uri = new_metadata_uri;
-    pokemon_data.hp = new_hp;
-    pokemon_data.attack = new_attack;
-    pokemon_data.defense = new_defense;
-    pokemon_data.speed = new_speed;
-    pokemon_data.evolution_stage = pokemon_data
-        .evolution_stage
-        .checked_add(1)
-        .ok_or(GameError::MathOverflow)?;
-    pokemon_data.level = pokemon_data
-        .level
-        .checked_add(1)
-        .ok_or(GameError::MathOverflow)?;
+    pokemon_data.current_metadata_uri = rule.metadata_uri.clone();
+    // New stage stats keep the bonus earned through levelling
+    let (hp_bonus, attack_bonus, defense_bonus, speed_bonus) =
+        leveling::level_bonus(pokemon_data.level);
+    pokemon_data.hp = rule.hp.saturating_add(hp_bonus);
+    pokemon_data.attack = rule.attack.saturating_add(attack_bonus);
+    pokemon_data.defense = rule.defense.saturating_add(defense_bonus);
+    pokemon_data.speed = rule.speed.saturating_add(speed_bonus);
+    pokemon_data.evolution_stage = rule.evolution_stage;
 
     msg!(
         "Pokemon evolved successfully to stage {}",
         pokemon_data.evolution_stage
     );
 
+    emit!(PokemonEvolved {
+        owner: ctx.accounts.player.key(),
+        mint: pokemon_data.mint,
+        evolution_stage: pokemon_data.evolution_stage,
+        soul_stones_burned: soul_stone_cost,
+    });
+
     Ok(())
 }
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{transfer_checked, close_account, Mint, Token, TokenAccount, TransferChecked, CloseAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::BattleExpired,
+    pokemon_lock::{self, PokemonLock}, PAUSE_BATTLES,
+};
+
+#[derive(Accounts)]
+pub struct ExpireBattle<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>, // Permissionless, receives the escrow rent
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        close = player1,
+        seeds = [b"battle", battle.battle_id.to_le_bytes().as_ref()],
+        bump = battle.bump
+    )]
+    pub battle: Account<'info, Battle>,
+
+    /// CHECK: Player 1 address, must match the battle record
+    #[account(
+        mut,
+        address = battle.player1 @ GameError::NotBattleParticipant
+    )]
+    pub player1: UncheckedAccount<'info>,
+
+    /// CHECK: Player 2 address, must match the battle record
+    #[account(
+        mut,
+        constraint = battle.player2 == Some(player2.key()) @ GameError::NotBattleParticipant
+    )]
+    pub player2: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
+    pub mon_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = battle
+    )]
+    pub battle_escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player1
+    )]
+    pub player1_mon_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player2
+    )]
+    pub player2_mon_account: Account<'info, TokenAccount>,
+
+    // Pokemon data, released from the battle lock
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player1_pokemon.as_ref()],
+        bump = player1_pokemon_data.bump
+    )]
+    pub player1_pokemon_data: Account<'info, PokemonData>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player2_pokemon.unwrap().as_ref()],
+        bump = player2_pokemon_data.bump
+    )]
+    pub player2_pokemon_data: Account<'info, PokemonData>,
+
+    // Player 1's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 1's Pokemon mint, must match the battle record
+    #[account(
+        address = battle.player1_pokemon @ GameError::InvalidPokemonMint
+    )]
+    pub player1_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player1_pokemon_mint,
+        token::authority = player1
+    )]
+    pub player1_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 1's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player1_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player1_pokemon_edition: UncheckedAccount<'info>,
+
+    // Player 2's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 2's Pokemon mint, must match the battle record
+    #[account(
+        constraint = battle.player2_pokemon == Some(player2_pokemon_mint.key()) @ GameError::InvalidPokemonMint
+    )]
+    pub player2_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player2_pokemon_mint,
+        token::authority = player2
+    )]
+    pub player2_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 2's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player2_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player2_pokemon_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn expire_battle(ctx: Context<ExpireBattle>) -> Result<()> {
+    let battle = &ctx.accounts.battle;
+
+    require!(
+        battle.status == BattleStatus::InProgress,
+        GameError::InvalidBattleStatus
+    );
+
+    // Both players committed and neither revealed in time: nobody can claim
+    // a forfeit, so the turn is refunded like an idle battle
+    let current_time = Clock::get()?.unix_timestamp;
+    let stalled_turn = battle.mode == BattleMode::TurnBased
+        && battle.player1_commitment.is_some()
+        && battle.player2_commitment.is_some()
+        && battle.player1_action.is_none()
+        && battle.player2_action.is_none()
+        && battle
+            .effective_turn_deadline(&ctx.accounts.platform)
+            .is_some_and(|deadline| current_time > deadline);
+
+    if !stalled_turn {
+        // Any other turn under way is settled by claim_forfeit once its
+        // deadline passes
+        if battle.mode == BattleMode::TurnBased {
+            require!(
+                battle.player1_commitment.is_none()
+                    && battle.player2_commitment.is_none()
+                    && battle.turn_deadline.is_none(),
+                GameError::TurnInProgress
+            );
+        }
+
+        // Idle time counts from the last turn played, or from the join, and
+        // excludes time spent paused
+        let deadline = battle
+            .idle_deadline(&ctx.accounts.platform)
+            .ok_or(GameError::BattleNotReady)?;
+        require!(current_time >= deadline, GameError::BattleNotExpired);
+    }
+
+    // Player 2 gets their stake back, player 1 gets the remainder of the escrow
+    let player2_refund = battle.stake_amount;
+    let player1_refund = ctx.accounts.battle_escrow.amount
+        .checked_sub(player2_refund)
+        .ok_or(GameError::MathOverflow)?;
+
+    let binding = battle.battle_id.to_le_bytes();
+    let battle_seeds = &[
+        b"battle".as_ref(),
+        binding.as_ref(),
+        &[battle.bump],
+    ];
+    let signer_seeds = &[&battle_seeds[..]];
+
+    for (to, amount) in [
+        (ctx.accounts.player1_mon_account.to_account_info(), player1_refund),
+        (ctx.accounts.player2_mon_account.to_account_info(), player2_refund),
+    ] {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.battle_escrow.to_account_info(),
+                    mint: ctx.accounts.mon_token_mint.to_account_info(),
+                    to,
+                    authority: ctx.accounts.battle.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            ctx.accounts.mon_token_mint.decimals,
+        )?;
+    }
+
+    msg!(
+        "Refunded {} MON tokens to player 1 and {} MON tokens to player 2",
+        player1_refund,
+        player2_refund
+    );
+
+    // Close escrow account
+    close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.battle_escrow.to_account_info(),
+                destination: ctx.accounts.caller.to_account_info(),
+                authority: ctx.accounts.battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+    )?;
+
+    // Release both Pokemon
+    pokemon_lock::unlock_pokemon(
+        PokemonLock {
+            platform: &ctx.accounts.platform,
+            token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+            mint: ctx.accounts.player1_pokemon_mint.to_account_info(),
+            edition: ctx.accounts.player1_pokemon_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        &mut ctx.accounts.player1_pokemon_data,
+    )?;
+    pokemon_lock::unlock_pokemon(
+        PokemonLock {
+            platform: &ctx.accounts.platform,
+            token_account: ctx.accounts.player2_pokemon_account.to_account_info(),
+            mint: ctx.accounts.player2_pokemon_mint.to_account_info(),
+            edition: ctx.accounts.player2_pokemon_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        &mut ctx.accounts.player2_pokemon_data,
+    )?;
+
+    let battle = &mut ctx.accounts.battle;
+    battle.status = BattleStatus::Cancelled;
+    battle.resolved_at = Some(current_time);
+
+    msg!("Battle {} expired without resolution", battle.battle_id);
+
+    emit!(BattleExpired {
+        battle: battle.key(),
+        battle_id: battle.battle_id,
+        player1_refund,
+        player2_refund,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::LocalRandomnessFulfilled};
+
+#[derive(Accounts)]
+pub struct FulfillLocalRandomness<'info> {
+    pub admin: Signer<'info>, // Stands in for the Switchboard oracle
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"local_randomness", local_randomness.randomness_id.to_le_bytes().as_ref()],
+        bump = local_randomness.bump
+    )]
+    pub local_randomness: Account<'info, LocalRandomness>,
+}
+
+pub fn fulfill_local_randomness(
+    ctx: Context<FulfillLocalRandomness>,
+    value: [u8; 32],
+) -> Result<()> {
+    let local_randomness = &mut ctx.accounts.local_randomness;
+
+    require!(
+        !local_randomness.revealed,
+        GameError::RandomnessAlreadyRevealed
+    );
+
+    local_randomness.value = value;
+    local_randomness.revealed = true;
+
+    msg!("Local randomness {} fulfilled", local_randomness.randomness_id);
+
+    emit!(LocalRandomnessFulfilled {
+        randomness: local_randomness.key(),
+        randomness_id: local_randomness.randomness_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::RoleGranted, ANCHOR_DISCRIMINATOR, MAX_ROLE_MEMBERS};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + RoleRegistry::INIT_SPACE,
+        seeds = [b"role_registry"],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_role(
+    ctx: Context<GrantRole>,
+    member: Pubkey,
+    role: Role,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.role_registry;
+    registry.bump = ctx.bumps.role_registry;
+
+    match registry.members.iter_mut().find(|entry| entry.authority == member) {
+        Some(entry) => entry.roles |= role.mask(),
+        None => {
+            require!(
+                registry.members.len() < MAX_ROLE_MEMBERS,
+                GameError::RoleRegistryFull
+            );
+            registry.members.push(RoleMember {
+                authority: member,
+                roles: role.mask(),
+            });
+        }
+    }
+
+    msg!("Role granted to {}", member);
+
+    emit!(RoleGranted { member, role });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::GameError, ANCHOR_DISCRIMINATOR};
+use crate::{state::*, errors::GameError, events::PlatformInitialized, ANCHOR_DISCRIMINATOR};
+use crate::{DEFAULT_BATTLE_TIMEOUT_SECONDS, DEFAULT_REVEAL_TIMEOUT_SECONDS, DEFAULT_MIN_BATTLE_STAKE, DEFAULT_MAX_BATTLE_STAKE, SWITCHBOARD_ON_DEMAND_PROGRAM_ID};
 
 #[derive(Accounts)]
 pub struct InitializePlatform<'info> {
@@ -38,21 +39,44 @@ pub fn initialize_platform(
 
     let platform = &mut ctx.accounts.platform;
     platform.admin = ctx.accounts.admin.key();
+    platform.pending_admin = None;
     platform.mon_token_mint = Pubkey::default();
     platform.soul_stone_mint = Pubkey::default();
+    platform.collection_mint = Pubkey::default();
     platform.soul_stone_price_lamports = 0;
     platform.platform_fee_percentage = platform_fee_percentage;
     platform.total_pokemon_minted = 0;
     platform.total_battles = 0;
+    platform.battle_timeout_seconds = DEFAULT_BATTLE_TIMEOUT_SECONDS;
+    platform.reveal_timeout_seconds = DEFAULT_REVEAL_TIMEOUT_SECONDS;
+    platform.min_battle_stake = DEFAULT_MIN_BATTLE_STAKE;
+    platform.max_battle_stake = DEFAULT_MAX_BATTLE_STAKE;
+    platform.pause_flags = 0;
+    platform.battles_paused_at = None;
+    platform.battle_pause_seconds = 0;
+    platform.randomness_oracle = SWITCHBOARD_ON_DEMAND_PROGRAM_ID; // Devnet deployments switch to the devnet program
     platform.bump = ctx.bumps.platform;
 
     let treasury = &mut ctx.accounts.treasury;
     treasury.total_fees_collected = 0;
     treasury.mon_token_vault = Pubkey::default();
+    treasury.total_mint_revenue = 0;
+    treasury.total_battle_fees = 0;
+    treasury.total_soul_stone_mon_revenue = 0;
+    treasury.total_mon_withdrawn = 0;
+    treasury.mon_balance = 0;
+    treasury.total_sol_collected = 0;
+    treasury.total_sol_withdrawn = 0;
+    treasury.revenue_splits = Vec::new();
     treasury.bump = ctx.bumps.treasury;
 
     msg!("Platform initialized with admin: {}", ctx.accounts.admin.key());
     msg!("Platform fee percentage: {}%", platform_fee_percentage as f64 / 100.0);
 
+    emit!(PlatformInitialized {
+        admin: ctx.accounts.admin.key(),
+        platform_fee_percentage,
+    });
+
     Ok(())
 }
\ No newline at end of file
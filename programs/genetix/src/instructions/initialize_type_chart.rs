@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TypeChartInitialized, ANCHOR_DISCRIMINATOR, TYPE_CHART_SIZE, TYPE_NEUTRAL_BPS};
+
+#[derive(Accounts)]
+pub struct InitializeTypeChart<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + TypeChart::INIT_SPACE,
+        seeds = [b"type_chart"],
+        bump
+    )]
+    pub type_chart: Box<Account<'info, TypeChart>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_type_chart(ctx: Context<InitializeTypeChart>) -> Result<()> {
+    let type_chart = &mut ctx.accounts.type_chart;
+    type_chart.multipliers = [TYPE_NEUTRAL_BPS; TYPE_CHART_SIZE];
+    type_chart.bump = ctx.bumps.type_chart;
+
+    msg!("Type chart initialized with neutral multipliers");
+
+    emit!(TypeChartInitialized {
+        type_chart: type_chart.key(),
+    });
+
+    Ok(())
+}
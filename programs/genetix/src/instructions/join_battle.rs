@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    metadata::Metadata,
     token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
-use crate::{state::*, errors::GameError};
+use crate::{
+    state::*, errors::GameError, events::BattleJoined, randomness,
+    pokemon_lock::{self, PokemonLock}, PAUSE_BATTLES,
+};
 
 #[derive(Accounts)]
 pub struct JoinBattle<'info> {
@@ -11,8 +15,10 @@ pub struct JoinBattle<'info> {
     pub player2: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -44,8 +50,9 @@ pub struct JoinBattle<'info> {
     )]
     pub battle_escrow: Account<'info, TokenAccount>,
 
-    // Pokemon verification — remove has_one and check at runtime
+    // Pokemon verification, locked into this battle until it ends
     #[account(
+        mut,
         seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
         bump = pokemon_data.bump
     )]
@@ -54,7 +61,40 @@ pub struct JoinBattle<'info> {
     /// CHECK: Pokemon mint address
     pub pokemon_mint: UncheckedAccount<'info>,
 
+    // Player 1's Pokemon, used to set up turn-based match state
+    #[account(
+        seeds = [b"pokemon_data", battle.player1_pokemon.as_ref()],
+        bump = player1_pokemon_data.bump
+    )]
+    pub player1_pokemon_data: Account<'info, PokemonData>,
+
+    /// CHECK: Pending randomness request from the platform's oracle, validated in the handler
+    pub randomness_account: UncheckedAccount<'info>,
+
+    // Ownership is proven by holding the NFT, not by PokemonData.owner
+    #[account(
+        mut,
+        token::mint = pokemon_mint,
+        token::authority = player2,
+        constraint = player2_pokemon_account.amount == 1 @ GameError::NotPokemonOwner
+    )]
+    pub player2_pokemon_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub pokemon_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -78,20 +118,36 @@ pub fn join_battle(
         GameError::InsufficientMonTokens
     );
 
-    // --- Ownership check (replace compile-time `has_one` with this runtime check)
+    // Direct challenges are reserved for the invited opponent
+    let terms = &battle.terms;
+    if let Some(invited_opponent) = terms.invited_opponent {
+        require_keys_eq!(
+            ctx.accounts.player2.key(),
+            invited_opponent,
+            GameError::NotInvitedOpponent
+        );
+    }
+    let pokemon = &ctx.accounts.pokemon_data;
+    require!(
+        terms.min_opponent_level.is_none_or(|level| pokemon.level >= level)
+            && terms
+                .min_opponent_evolution_stage
+                .is_none_or(|stage| pokemon.evolution_stage >= stage),
+        GameError::ChallengeRequirementsNotMet
+    );
+
+    // Ensure the supplied mint argument matches the verified accounts
     require_keys_eq!(
-        ctx.accounts.pokemon_data.owner,
-        ctx.accounts.player2.key(),
-        GameError::NotPokemonOwner
+        ctx.accounts.pokemon_mint.key(),
+        pokemon_mint,
+        GameError::InvalidPokemonMint
     );
 
-    // --- Optional: ensure the pokemon_data actually corresponds to the supplied mint
-    // (add a GameError variant like InvalidPokemonMint if you want to fail with a clear error)
-    // require_keys_eq!(
-    //     ctx.accounts.pokemon_data.mint,
-    //     pokemon_mint,
-    //     GameError::InvalidPokemonMint
-    // );
+    // Commit to randomness nobody knows yet, so neither side can pick an outcome
+    let randomness_request_slot = randomness::pending_request_slot(
+        &ctx.accounts.randomness_account,
+        &ctx.accounts.platform.randomness_oracle,
+    )?;
 
     // Transfer stake to escrow
     transfer(
@@ -108,14 +164,51 @@ pub fn join_battle(
 
     msg!("Player 2 staked {} MON tokens", battle.stake_amount);
 
+    // Lock the Pokemon into this battle and freeze its NFT
+    pokemon_lock::lock_pokemon(
+        PokemonLock {
+            platform: &ctx.accounts.platform,
+            token_account: ctx.accounts.player2_pokemon_account.to_account_info(),
+            mint: ctx.accounts.pokemon_mint.to_account_info(),
+            edition: ctx.accounts.pokemon_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        ctx.accounts.player2.to_account_info(),
+        &mut ctx.accounts.pokemon_data,
+        ctx.accounts.battle.key(),
+    )?;
+
     // Update battle
     let battle = &mut ctx.accounts.battle;
     battle.player2 = Some(ctx.accounts.player2.key());
     battle.player2_pokemon = Some(pokemon_mint);
     battle.status = BattleStatus::InProgress;
+    battle.joined_at = Some(Clock::get()?.unix_timestamp);
+    battle.turn_pause_seconds = ctx.accounts.platform.battle_pause_seconds;
+    battle.randomness_account = Some(ctx.accounts.randomness_account.key());
+    battle.randomness_request_slot = randomness_request_slot;
+
+    if battle.mode == BattleMode::TurnBased {
+        let player1_pokemon = &ctx.accounts.player1_pokemon_data;
+        let player2_pokemon = &ctx.accounts.pokemon_data;
+
+        battle.player1_hp = player1_pokemon.hp;
+        battle.player2_hp = player2_pokemon.hp;
+        battle.turn = 0;
+        battle.player1_pp = Battle::starting_pp(player1_pokemon);
+        battle.player2_pp = Battle::starting_pp(player2_pokemon);
+    }
 
     msg!("Player 2 joined battle {}", battle.battle_id);
     msg!("Battle is now in progress!");
 
+    emit!(BattleJoined {
+        battle: battle.key(),
+        battle_id: battle.battle_id,
+        player2: ctx.accounts.player2.key(),
+        pokemon_mint,
+    });
+
     Ok(())
 }
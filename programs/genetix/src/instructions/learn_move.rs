@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::{state::*, errors::GameError, events::MoveLearned, MAX_MOVES_PER_POKEMON};
+
+#[derive(Accounts)]
+pub struct LearnMove<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        address = pokemon_data.mint
+    )]
+    pub pokemon_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
+        bump = pokemon_data.bump,
+        constraint = pokemon_data.active_battle.is_none() @ GameError::PokemonInBattle
+    )]
+    pub pokemon_data: Account<'info, PokemonData>,
+
+    #[account(
+        token::mint = pokemon_mint,
+        token::authority = owner,
+        constraint = owner_pokemon_account.amount == 1 @ GameError::NotPokemonOwner
+    )]
+    pub owner_pokemon_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"move", move_account.move_id.to_le_bytes().as_ref()],
+        bump = move_account.bump
+    )]
+    pub move_account: Account<'info, Move>,
+}
+
+/// Learn into `slot`: an occupied slot is replaced, the next free slot appends
+pub fn learn_move(
+    ctx: Context<LearnMove>,
+    slot: u8,
+) -> Result<()> {
+    let move_account = &ctx.accounts.move_account;
+    let pokemon_data = &mut ctx.accounts.pokemon_data;
+    let slot_index = slot as usize;
+
+    require!(
+        pokemon_data.level >= move_account.min_level,
+        GameError::LevelTooLow
+    );
+    if let Some(template_id) = move_account.template_id {
+        require!(
+            pokemon_data.template_id == template_id,
+            GameError::MoveNotLearnable
+        );
+    }
+    require!(
+        !pokemon_data
+            .moves
+            .iter()
+            .any(|known| known.move_id == move_account.move_id),
+        GameError::MoveAlreadyKnown
+    );
+    require!(
+        slot_index < MAX_MOVES_PER_POKEMON && slot_index <= pokemon_data.moves.len(),
+        GameError::InvalidMoveSlot
+    );
+
+    let known_move = KnownMove {
+        move_id: move_account.move_id,
+        power: move_account.power,
+        accuracy: move_account.accuracy,
+        element_type: move_account.element_type,
+        pp: move_account.pp,
+    };
+
+    let replaced_move_id = if slot_index < pokemon_data.moves.len() {
+        let replaced = pokemon_data.moves[slot_index].move_id;
+        pokemon_data.moves[slot_index] = known_move;
+        Some(replaced)
+    } else {
+        pokemon_data.moves.push(known_move);
+        None
+    };
+
+    msg!(
+        "Pokemon {} learned move {} in slot {}",
+        pokemon_data.mint,
+        move_account.move_id,
+        slot
+    );
+
+    emit!(MoveLearned {
+        mint: pokemon_data.mint,
+        move_id: move_account.move_id,
+        slot,
+        replaced_move_id,
+    });
+
+    Ok(())
+}
@@ -2,13 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::{
-        create_master_edition_v3, create_metadata_accounts_v3,
-        mpl_token_metadata::types::DataV2, CreateMasterEditionV3, CreateMetadataAccountsV3,
-        Metadata,
+        create_master_edition_v3, create_metadata_accounts_v3, verify_sized_collection_item,
+        mpl_token_metadata::types::{Collection, DataV2}, CreateMasterEditionV3,
+        CreateMetadataAccountsV3, Metadata, VerifySizedCollectionItem,
     },
     token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer},
 };
-use crate::{state::*, errors::GameError, ANCHOR_DISCRIMINATOR};
+use crate::{state::*, errors::GameError, events::PokemonMinted, ANCHOR_DISCRIMINATOR, PAUSE_MINTING};
 
 #[derive(Accounts)]
 #[instruction(template_id: u64)]
@@ -19,7 +19,8 @@ pub struct MintPokemon<'info> {
     #[account(
         mut,
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_MINTING) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -30,8 +31,18 @@ pub struct MintPokemon<'info> {
     )]
     pub pokemon_template: Account<'info, PokemonTemplate>,
 
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, PlatformTreasury>>,
+
     // MON token payment
-    #[account(mut)]
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
     pub mon_token_mint: Account<'info, Mint>,
 
     #[account(
@@ -45,7 +56,8 @@ pub struct MintPokemon<'info> {
         init_if_needed,
         payer = player,
         associated_token::mint = mon_token_mint,
-        associated_token::authority = platform
+        associated_token::authority = platform,
+        address = treasury.mon_token_vault
     )]
     pub platform_mon_account: Account<'info, TokenAccount>,
 
@@ -94,6 +106,39 @@ pub struct MintPokemon<'info> {
     )]
     pub edition_account: UncheckedAccount<'info>,
 
+    // Collection the Pokemon is verified into: the template's sub-collection
+    // if it has one, otherwise the Genetix collection
+    #[account(
+        address = pokemon_template.collection_mint.unwrap_or(platform.collection_mint) @ GameError::InvalidCollection
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = player,
@@ -117,6 +162,18 @@ pub fn mint_pokemon(
     let template = &ctx.accounts.pokemon_template;
     
     require!(template.is_active, GameError::TemplateNotFound);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if let Some(max_supply) = template.max_supply {
+        require!(template.times_minted < max_supply, GameError::MaxSupplyReached);
+    }
+    if let Some(mint_start_ts) = template.mint_start_ts {
+        require!(current_time >= mint_start_ts, GameError::MintNotStarted);
+    }
+    if let Some(mint_end_ts) = template.mint_end_ts {
+        require!(current_time < mint_end_ts, GameError::MintEnded);
+    }
+
     require!(
         ctx.accounts.player_mon_account.amount >= template.price_in_mon,
         GameError::InsufficientMonTokens
@@ -152,19 +209,28 @@ pub fn mint_pokemon(
 
     msg!("Minted Pokemon NFT");
 
+    // Platform PDA is the metadata update authority so that metadata can
+    // only change through this program
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[ctx.accounts.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
     // Create metadata
     create_metadata_accounts_v3(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_metadata_program.to_account_info(),
             CreateMetadataAccountsV3 {
                 metadata: ctx.accounts.metadata_account.to_account_info(),
                 mint: ctx.accounts.pokemon_mint.to_account_info(),
                 mint_authority: ctx.accounts.player.to_account_info(),
-                update_authority: ctx.accounts.player.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
                 payer: ctx.accounts.player.to_account_info(),
                 system_program: ctx.accounts.system_program.to_account_info(),
                 rent: ctx.accounts.rent.to_account_info(),
             },
+            signer_seeds,
         ),
         DataV2 {
             name: template.name.clone(),
@@ -172,7 +238,10 @@ pub fn mint_pokemon(
             uri: template.base_uri.clone(),
             seller_fee_basis_points: 0,
             creators: None,
-            collection: None,
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
             uses: None,
         },
         true, // Is mutable
@@ -184,12 +253,12 @@ pub fn mint_pokemon(
 
     // Create master edition
     create_master_edition_v3(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_metadata_program.to_account_info(),
             CreateMasterEditionV3 {
                 edition: ctx.accounts.edition_account.to_account_info(),
                 mint: ctx.accounts.pokemon_mint.to_account_info(),
-                update_authority: ctx.accounts.player.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
                 mint_authority: ctx.accounts.player.to_account_info(),
                 payer: ctx.accounts.player.to_account_info(),
                 metadata: ctx.accounts.metadata_account.to_account_info(),
@@ -197,12 +266,32 @@ pub fn mint_pokemon(
                 system_program: ctx.accounts.system_program.to_account_info(),
                 rent: ctx.accounts.rent.to_account_info(),
             },
+            signer_seeds,
         ),
         None, // Max Supply
     )?;
 
     msg!("Created master edition");
 
+    // Verify the Pokemon into its collection
+    verify_sized_collection_item(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            VerifySizedCollectionItem {
+                payer: ctx.accounts.player.to_account_info(),
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                collection_authority: ctx.accounts.platform.to_account_info(),
+                collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        None, // Collection authority record
+    )?;
+
+    msg!("Verified Pokemon in collection {}", ctx.accounts.collection_mint.key());
+
     // Initialize Pokemon data
     let pokemon_data = &mut ctx.accounts.pokemon_data;
     pokemon_data.owner = ctx.accounts.player.key();
@@ -214,12 +303,17 @@ pub fn mint_pokemon(
     pokemon_data.attack = template.attack;
     pokemon_data.defense = template.defense;
     pokemon_data.speed = template.speed;
+    pokemon_data.primary_type = template.primary_type;
+    pokemon_data.secondary_type = template.secondary_type;
     pokemon_data.level = 1;
+    pokemon_data.experience = 0;
+    pokemon_data.moves = Vec::new();
     pokemon_data.evolution_stage = 0;
     pokemon_data.battles_won = 0;
     pokemon_data.battles_lost = 0;
-    pokemon_data.created_at = Clock::get()?.unix_timestamp;
+    pokemon_data.created_at = current_time;
     pokemon_data.last_battle_at = 0;
+    pokemon_data.active_battle = None;
     pokemon_data.bump = ctx.bumps.pokemon_data;
 
     // Update counters
@@ -231,7 +325,27 @@ pub fn mint_pokemon(
         .checked_add(1)
         .ok_or(GameError::MathOverflow)?;
 
+    // Record mint revenue in the treasury
+    let price_in_mon = ctx.accounts.pokemon_template.price_in_mon;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_mint_revenue = treasury.total_mint_revenue
+        .checked_add(price_in_mon)
+        .ok_or(GameError::MathOverflow)?;
+    treasury.total_fees_collected = treasury.total_fees_collected
+        .checked_add(price_in_mon)
+        .ok_or(GameError::MathOverflow)?;
+    treasury.mon_balance = treasury.mon_balance
+        .checked_add(price_in_mon)
+        .ok_or(GameError::MathOverflow)?;
+
     msg!("Pokemon minted successfully: {}", ctx.accounts.pokemon_mint.key());
 
+    emit!(PokemonMinted {
+        owner: ctx.accounts.player.key(),
+        mint: ctx.accounts.pokemon_mint.key(),
+        template_id,
+        price_in_mon,
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -2,9 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::SoulStoneMinted,
+    soul_stones::{self, DeliverSoulStones}, ANCHOR_DISCRIMINATOR, PAUSE_SOUL_STONES,
 };
-use crate::{state::*, errors::GameError};
 
 #[derive(Accounts)]
 pub struct MintSoulStone<'info> {
@@ -13,7 +16,8 @@ pub struct MintSoulStone<'info> {
 
     #[account(
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_SOUL_STONES) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -38,67 +42,92 @@ pub struct MintSoulStone<'info> {
     )]
     pub player_soul_stone_account: Account<'info, TokenAccount>,
 
-    /// CHECK: Admin account to receive SOL payment
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = ANCHOR_DISCRIMINATOR + SoulStonePurchaseRecord::INIT_SPACE,
+        seeds = [b"soul_stone_purchases", player.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, SoulStonePurchaseRecord>,
+
+    // Treasury PDA receives the SOL payment
     #[account(
         mut,
-        address = platform.admin
+        seeds = [b"treasury"],
+        bump = treasury.bump
     )]
-    pub admin: UncheckedAccount<'info>,
+    pub treasury: Account<'info, PlatformTreasury>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn mint_soul_stone(ctx: Context<MintSoulStone>) -> Result<()> {
-    let price = ctx.accounts.soul_stone_config.price_in_lamports;
-    
-    require!(price > 0, GameError::InvalidPrice);
+pub fn mint_soul_stone(
+    ctx: Context<MintSoulStone>,
+    quantity: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.soul_stone_config;
+    let unit_price = config.price_in_lamports;
+
+    require!(quantity > 0, GameError::InvalidQuantity);
+    require!(unit_price > 0, GameError::InvalidPrice);
+
+    let price = config
+        .bulk_price(unit_price, quantity)
+        .ok_or(GameError::MathOverflow)?;
+
+    let daily_purchase = soul_stones::check_daily_cap(
+        config,
+        &ctx.accounts.purchase_record,
+        quantity,
+    )?;
+
     require!(
         ctx.accounts.player.lamports() >= price,
         GameError::InsufficientSol
     );
 
-    // Transfer SOL to admin
+    // Transfer SOL to treasury
     system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             system_program::Transfer {
                 from: ctx.accounts.player.to_account_info(),
-                to: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
             },
         ),
         price,
     )?;
 
-    msg!("Paid {} lamports for Soul Stone", price);
-
-    // Mint Soul Stone to player
-    let platform_seeds = &[
-        b"platform".as_ref(),
-        &[ctx.accounts.platform.bump],
-    ];
-    let signer_seeds = &[&platform_seeds[..]];
-
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.soul_stone_mint.to_account_info(),
-                to: ctx.accounts.player_soul_stone_account.to_account_info(),
-                authority: ctx.accounts.platform.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        1,
-    )?;
-
-    // Update counter
-    ctx.accounts.soul_stone_config.total_minted = ctx.accounts.soul_stone_config.total_minted
-        .checked_add(1)
+    ctx.accounts.treasury.total_sol_collected = ctx.accounts.treasury.total_sol_collected
+        .checked_add(price)
         .ok_or(GameError::MathOverflow)?;
 
-    msg!("Soul Stone minted successfully to player");
+    msg!("Paid {} lamports for {} Soul Stone(s)", price, quantity);
+
+    // Mint Soul Stones to player and record the purchase
+    soul_stones::deliver_soul_stones(
+        DeliverSoulStones {
+            player: ctx.accounts.player.key(),
+            platform: &ctx.accounts.platform,
+            soul_stone_config: &mut ctx.accounts.soul_stone_config,
+            soul_stone_mint: &ctx.accounts.soul_stone_mint,
+            player_soul_stone_account: &ctx.accounts.player_soul_stone_account,
+            purchase_record: &mut ctx.accounts.purchase_record,
+            purchase_record_bump: ctx.bumps.purchase_record,
+            token_program: &ctx.accounts.token_program,
+        },
+        quantity,
+        daily_purchase,
+    )?;
+
+    emit!(SoulStoneMinted {
+        player: ctx.accounts.player.key(),
+        amount: quantity,
+        price_in_lamports: price,
+    });
 
     Ok(())
 }
\ No newline at end of file
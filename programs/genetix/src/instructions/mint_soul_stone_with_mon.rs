@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+use crate::{
+    state::*, errors::GameError, events::SoulStoneMintedWithMon,
+    soul_stones::{self, DeliverSoulStones}, ANCHOR_DISCRIMINATOR, PAUSE_SOUL_STONES,
+};
+
+#[derive(Accounts)]
+pub struct MintSoulStoneWithMon<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_SOUL_STONES) @ GameError::Paused
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stone_config"],
+        bump = soul_stone_config.bump
+    )]
+    pub soul_stone_config: Account<'info, SoulStoneConfig>,
+
+    #[account(
+        mut,
+        address = platform.soul_stone_mint
+    )]
+    pub soul_stone_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = soul_stone_mint,
+        associated_token::authority = player
+    )]
+    pub player_soul_stone_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = ANCHOR_DISCRIMINATOR + SoulStonePurchaseRecord::INIT_SPACE,
+        seeds = [b"soul_stone_purchases", player.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, SoulStonePurchaseRecord>,
+
+    // MON payment
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
+    pub mon_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player
+    )]
+    pub player_mon_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = treasury.mon_token_vault
+    )]
+    pub platform_mon_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, PlatformTreasury>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mint_soul_stone_with_mon(
+    ctx: Context<MintSoulStoneWithMon>,
+    quantity: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.soul_stone_config;
+    let unit_price = config.price_in_mon.ok_or(GameError::MonPriceNotSet)?;
+    let mon_payment_mode = config.mon_payment_mode;
+
+    require!(quantity > 0, GameError::InvalidQuantity);
+    require!(unit_price > 0, GameError::InvalidPrice);
+
+    let price = config
+        .bulk_price(unit_price, quantity)
+        .ok_or(GameError::MathOverflow)?;
+
+    let daily_purchase = soul_stones::check_daily_cap(
+        config,
+        &ctx.accounts.purchase_record,
+        quantity,
+    )?;
+
+    require!(
+        ctx.accounts.player_mon_account.amount >= price,
+        GameError::InsufficientMonTokens
+    );
+
+    match mon_payment_mode {
+        MonPaymentMode::Burn => {
+            burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mon_token_mint.to_account_info(),
+                        from: ctx.accounts.player_mon_account.to_account_info(),
+                        authority: ctx.accounts.player.to_account_info(),
+                    },
+                ),
+                price,
+            )?;
+
+            let config = &mut ctx.accounts.soul_stone_config;
+            config.total_mon_burned = config.total_mon_burned
+                .checked_add(price)
+                .ok_or(GameError::MathOverflow)?;
+
+            msg!("Burned {} MON tokens for {} Soul Stone(s)", price, quantity);
+        }
+        MonPaymentMode::Treasury => {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.player_mon_account.to_account_info(),
+                        to: ctx.accounts.platform_mon_account.to_account_info(),
+                        authority: ctx.accounts.player.to_account_info(),
+                    },
+                ),
+                price,
+            )?;
+
+            let treasury = &mut ctx.accounts.treasury;
+            treasury.total_fees_collected = treasury.total_fees_collected
+                .checked_add(price)
+                .ok_or(GameError::MathOverflow)?;
+            treasury.total_soul_stone_mon_revenue = treasury.total_soul_stone_mon_revenue
+                .checked_add(price)
+                .ok_or(GameError::MathOverflow)?;
+            treasury.mon_balance = treasury.mon_balance
+                .checked_add(price)
+                .ok_or(GameError::MathOverflow)?;
+
+            msg!("Paid {} MON tokens for {} Soul Stone(s)", price, quantity);
+        }
+    }
+
+    // Mint Soul Stones to player and record the purchase
+    soul_stones::deliver_soul_stones(
+        DeliverSoulStones {
+            player: ctx.accounts.player.key(),
+            platform: &ctx.accounts.platform,
+            soul_stone_config: &mut ctx.accounts.soul_stone_config,
+            soul_stone_mint: &ctx.accounts.soul_stone_mint,
+            player_soul_stone_account: &ctx.accounts.player_soul_stone_account,
+            purchase_record: &mut ctx.accounts.purchase_record,
+            purchase_record_bump: ctx.bumps.purchase_record,
+            token_program: &ctx.accounts.token_program,
+        },
+        quantity,
+        daily_purchase,
+    )?;
+
+    emit!(SoulStoneMintedWithMon {
+        player: ctx.accounts.player.key(),
+        amount: quantity,
+        price_in_mon: price,
+        mon_payment_mode,
+    });
+
+    Ok(())
+}
@@ -13,6 +13,43 @@ pub mod resolve_battle;
 pub mod reward_mon_tokens;
 pub mod update_pokemon_metadata;
 pub mod withdraw_platform_fees;
+pub mod add_resolver;
+pub mod remove_resolver;
+pub mod set_battle_timeout;
+pub mod cancel_battle;
+pub mod expire_battle;
+pub mod set_battle_stake_limits;
+pub mod sync_pokemon_owner;
+pub mod add_evolution_rule;
+pub mod create_collection;
+pub mod create_template_collection;
+pub mod update_pokemon_template;
+pub mod set_template_active;
+pub mod set_template_mint_limits;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_pause;
+pub mod set_revenue_splits;
+pub mod withdraw_sol;
+pub mod set_soul_stone_bulk_pricing;
+pub mod set_soul_stone_mon_price;
+pub mod mint_soul_stone_with_mon;
+pub mod set_template_types;
+pub mod initialize_type_chart;
+pub mod set_type_effectiveness;
+pub mod add_move;
+pub mod learn_move;
+pub mod set_reveal_timeout;
+pub mod commit_turn;
+pub mod reveal_turn;
+pub mod claim_forfeit;
+pub mod set_randomness_oracle;
+#[cfg(feature = "localnet")]
+pub mod request_local_randomness;
+#[cfg(feature = "localnet")]
+pub mod fulfill_local_randomness;
 
 pub use initialize_platform::*;
 pub use create_mon_token::*;
@@ -28,4 +65,41 @@ pub use join_battle::*;
 pub use resolve_battle::*;
 pub use reward_mon_tokens::*;
 pub use update_pokemon_metadata::*;
-pub use withdraw_platform_fees::*;
\ No newline at end of file
+pub use withdraw_platform_fees::*;
+pub use add_resolver::*;
+pub use remove_resolver::*;
+pub use set_battle_timeout::*;
+pub use cancel_battle::*;
+pub use expire_battle::*;
+pub use set_battle_stake_limits::*;
+pub use sync_pokemon_owner::*;
+pub use add_evolution_rule::*;
+pub use create_collection::*;
+pub use create_template_collection::*;
+pub use update_pokemon_template::*;
+pub use set_template_active::*;
+pub use set_template_mint_limits::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_pause::*;
+pub use set_revenue_splits::*;
+pub use withdraw_sol::*;
+pub use set_soul_stone_bulk_pricing::*;
+pub use set_soul_stone_mon_price::*;
+pub use mint_soul_stone_with_mon::*;
+pub use set_template_types::*;
+pub use initialize_type_chart::*;
+pub use set_type_effectiveness::*;
+pub use add_move::*;
+pub use learn_move::*;
+pub use set_reveal_timeout::*;
+pub use commit_turn::*;
+pub use reveal_turn::*;
+pub use claim_forfeit::*;
+pub use set_randomness_oracle::*;
+#[cfg(feature = "localnet")]
+pub use request_local_randomness::*;
+#[cfg(feature = "localnet")]
+pub use fulfill_local_randomness::*;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::AdminProposed};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn propose_admin(
+    ctx: Context<ProposeAdmin>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    ctx.accounts.platform.pending_admin = Some(new_admin);
+
+    msg!("Admin handover proposed to: {}", new_admin);
+
+    emit!(AdminProposed {
+        admin: ctx.accounts.admin.key(),
+        proposed_admin: new_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::ResolverRemoved};
+
+#[derive(Accounts)]
+pub struct RemoveResolver<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"resolver_registry"],
+        bump = resolver_registry.bump
+    )]
+    pub resolver_registry: Account<'info, ResolverRegistry>,
+}
+
+pub fn remove_resolver(
+    ctx: Context<RemoveResolver>,
+    resolver: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.resolver_registry;
+
+    let index = registry
+        .resolvers
+        .iter()
+        .position(|key| *key == resolver)
+        .ok_or(GameError::ResolverNotFound)?;
+    registry.resolvers.swap_remove(index);
+
+    msg!("Resolver removed: {}", resolver);
+
+    emit!(ResolverRemoved { resolver });
+
+    Ok(())
+}
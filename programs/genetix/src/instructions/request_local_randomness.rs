@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, events::LocalRandomnessRequested, ANCHOR_DISCRIMINATOR};
+
+#[derive(Accounts)]
+#[instruction(randomness_id: u64)]
+pub struct RequestLocalRandomness<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = ANCHOR_DISCRIMINATOR + LocalRandomness::INIT_SPACE,
+        seeds = [b"local_randomness", randomness_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub local_randomness: Account<'info, LocalRandomness>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_local_randomness(
+    ctx: Context<RequestLocalRandomness>,
+    randomness_id: u64,
+) -> Result<()> {
+    let local_randomness = &mut ctx.accounts.local_randomness;
+    local_randomness.randomness_id = randomness_id;
+    local_randomness.requested_slot = Clock::get()?.slot;
+    local_randomness.value = [0; 32];
+    local_randomness.revealed = false;
+    local_randomness.bump = ctx.bumps.local_randomness;
+
+    msg!("Local randomness {} requested", randomness_id);
+
+    emit!(LocalRandomnessRequested {
+        randomness: local_randomness.key(),
+        randomness_id,
+    });
+
+    Ok(())
+}
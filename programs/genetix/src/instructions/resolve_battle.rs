@@ -1,19 +1,31 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, close_account, Mint, Token, TokenAccount, TransferChecked, CloseAccount},
+    metadata::Metadata,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::BattleResolved, battle_engine::{self, Combatant},
+    randomness, settlement::{self, SettleBattle}, pokemon_lock::PokemonLock, PAUSE_BATTLES,
 };
-use crate::{state::*, errors::GameError};
 
 #[derive(Accounts)]
 pub struct ResolveBattle<'info> {
     #[account(mut)]
-    pub resolver: Signer<'info>, // Could be admin or oracle
+    pub resolver: Signer<'info>, // Must be a registered oracle
+
+    #[account(
+        seeds = [b"resolver_registry"],
+        bump = resolver_registry.bump,
+        constraint = resolver_registry.resolvers.contains(&resolver.key()) @ GameError::UnauthorizedResolver
+    )]
+    pub resolver_registry: Account<'info, ResolverRegistry>,
 
     #[account(
         mut,
         seeds = [b"platform"],
-        bump = platform.bump
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
     )]
     pub platform: Account<'info, Platform>,
 
@@ -32,12 +44,18 @@ pub struct ResolveBattle<'info> {
     )]
     pub battle: Account<'info, Battle>,
 
-    /// CHECK: Player 1 address
-    #[account(mut)]
+    /// CHECK: Player 1 address, must match the battle record
+    #[account(
+        mut,
+        address = battle.player1 @ GameError::NotBattleParticipant
+    )]
     pub player1: UncheckedAccount<'info>,
 
-    /// CHECK: Player 2 address
-    #[account(mut)]
+    /// CHECK: Player 2 address, must match the battle record
+    #[account(
+        mut,
+        constraint = battle.player2 == Some(player2.key()) @ GameError::NotBattleParticipant
+    )]
     pub player2: UncheckedAccount<'info>,
 
     // MON token accounts
@@ -74,7 +92,8 @@ pub struct ResolveBattle<'info> {
         init_if_needed,
         payer = resolver,
         associated_token::mint = mon_token_mint,
-        associated_token::authority = platform
+        associated_token::authority = platform,
+        address = treasury.mon_token_vault
     )]
     pub platform_fee_account: Account<'info, TokenAccount>,
 
@@ -93,15 +112,79 @@ pub struct ResolveBattle<'info> {
     )]
     pub player2_pokemon_data: Account<'info, PokemonData>,
 
+    #[account(
+        seeds = [b"type_chart"],
+        bump = type_chart.bump
+    )]
+    pub type_chart: Box<Account<'info, TypeChart>>,
+
+    /// CHECK: Randomness account committed at join, validated in the handler
+    #[account(
+        constraint = battle.randomness_account == Some(randomness_account.key()) @ GameError::InvalidRandomnessAccount
+    )]
+    pub randomness_account: UncheckedAccount<'info>,
+
+    // Player 1's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 1's Pokemon mint, must match the battle record
+    #[account(
+        address = battle.player1_pokemon @ GameError::InvalidPokemonMint
+    )]
+    pub player1_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player1_pokemon_mint,
+        token::authority = player1
+    )]
+    pub player1_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 1's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player1_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player1_pokemon_edition: UncheckedAccount<'info>,
+
+    // Player 2's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 2's Pokemon mint, must match the battle record
+    #[account(
+        constraint = battle.player2_pokemon == Some(player2_pokemon_mint.key()) @ GameError::InvalidPokemonMint
+    )]
+    pub player2_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player2_pokemon_mint,
+        token::authority = player2
+    )]
+    pub player2_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 2's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player2_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player2_pokemon_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn resolve_battle(
-    ctx: Context<ResolveBattle>,
-    winner_is_player1: bool,
-) -> Result<()> {
+pub fn resolve_battle(ctx: Context<ResolveBattle>) -> Result<()> {
     let battle = &ctx.accounts.battle;
 
     require!(
@@ -112,120 +195,79 @@ pub fn resolve_battle(
         battle.player2.is_some(),
         GameError::BattleNotReady
     );
+    require!(
+        battle.mode == BattleMode::Simulated,
+        GameError::InvalidBattleMode
+    );
 
-    let total_pot = ctx.accounts.battle_escrow.amount;
-    let platform_fee = battle.platform_fee_amount;
-    let winner_amount = total_pot
-        .checked_sub(platform_fee)
-        .ok_or(GameError::MathOverflow)?;
-
-    let binding = battle.battle_id.to_le_bytes();
-    let battle_seeds = &[
-        b"battle".as_ref(),
-        binding.as_ref(),
-        &[battle.bump],
-    ];
-    let signer_seeds = &[&battle_seeds[..]];
-
-    // Transfer platform fee
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.battle_escrow.to_account_info(),
-                mint: ctx.accounts.mon_token_mint.to_account_info(),
-                to: ctx.accounts.platform_fee_account.to_account_info(),
-                authority: ctx.accounts.battle.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        platform_fee,
-        ctx.accounts.mon_token_mint.decimals,
+    let randomness = randomness::revealed_value(
+        &ctx.accounts.randomness_account,
+        &ctx.accounts.platform.randomness_oracle,
+        ctx.accounts.battle.randomness_request_slot,
     )?;
+    ctx.accounts.battle.randomness = Some(randomness);
+    let battle = &ctx.accounts.battle;
 
-    msg!("Platform fee collected: {}", platform_fee);
-
-    // Transfer winnings to winner
-    let winner_account = if winner_is_player1 {
-        &ctx.accounts.player1_mon_account
-    } else {
-        &ctx.accounts.player2_mon_account
-    };
-
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.battle_escrow.to_account_info(),
-                mint: ctx.accounts.mon_token_mint.to_account_info(),
-                to: winner_account.to_account_info(),
-                authority: ctx.accounts.battle.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        winner_amount,
-        ctx.accounts.mon_token_mint.decimals,
-    )?;
+    // Simulate the battle from on-chain stats, types, the committed seed and
+    // the oracle's randomness
+    let outcome = battle_engine::simulate_battle(
+        &Combatant::from(&*ctx.accounts.player1_pokemon_data),
+        &Combatant::from(&*ctx.accounts.player2_pokemon_data),
+        &ctx.accounts.type_chart,
+        &battle_engine::mix_randomness(&battle.seed, &randomness),
+    );
 
-    msg!("Winner received: {} MON tokens", winner_amount);
+    msg!(
+        "Battle simulated over {} rounds (HP left: {} vs {})",
+        outcome.rounds,
+        outcome.player1_hp_remaining,
+        outcome.player2_hp_remaining
+    );
 
-    // Close escrow account
-    close_account(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            CloseAccount {
-                account: ctx.accounts.battle_escrow.to_account_info(),
-                destination: ctx.accounts.resolver.to_account_info(),
-                authority: ctx.accounts.battle.to_account_info(),
+    let settlement = settlement::settle_battle(
+        SettleBattle {
+            battle: &mut ctx.accounts.battle,
+            treasury: &mut ctx.accounts.treasury,
+            battle_escrow: &ctx.accounts.battle_escrow,
+            mon_token_mint: &ctx.accounts.mon_token_mint,
+            platform_fee_account: &ctx.accounts.platform_fee_account,
+            player1_mon_account: &ctx.accounts.player1_mon_account,
+            player2_mon_account: &ctx.accounts.player2_mon_account,
+            player1_pokemon_data: &mut ctx.accounts.player1_pokemon_data,
+            player2_pokemon_data: &mut ctx.accounts.player2_pokemon_data,
+            player1_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player1_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player1_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            },
+            player2_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player2_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player2_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player2_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
             },
-            signer_seeds,
-        ),
+            escrow_rent_destination: ctx.accounts.resolver.to_account_info(),
+            token_program: &ctx.accounts.token_program,
+        },
+        outcome.winner_is_player1,
     )?;
 
-    // Update Pokemon stats
-    let current_time = Clock::get()?.unix_timestamp;
-    
-    if winner_is_player1 {
-        ctx.accounts.player1_pokemon_data.battles_won = ctx.accounts.player1_pokemon_data.battles_won
-            .checked_add(1)
-            .ok_or(GameError::MathOverflow)?;
-        ctx.accounts.player1_pokemon_data.last_battle_at = current_time;
-
-        ctx.accounts.player2_pokemon_data.battles_lost = ctx.accounts.player2_pokemon_data.battles_lost
-            .checked_add(1)
-            .ok_or(GameError::MathOverflow)?;
-        ctx.accounts.player2_pokemon_data.last_battle_at = current_time;
-    } else {
-        ctx.accounts.player2_pokemon_data.battles_won = ctx.accounts.player2_pokemon_data.battles_won
-            .checked_add(1)
-            .ok_or(GameError::MathOverflow)?;
-        ctx.accounts.player2_pokemon_data.last_battle_at = current_time;
-
-        ctx.accounts.player1_pokemon_data.battles_lost = ctx.accounts.player1_pokemon_data.battles_lost
-            .checked_add(1)
-            .ok_or(GameError::MathOverflow)?;
-        ctx.accounts.player1_pokemon_data.last_battle_at = current_time;
-    }
-
-    // Update battle status
-    let battle = &mut ctx.accounts.battle;
-    battle.status = BattleStatus::Resolved;
-    battle.winner = Some(if winner_is_player1 {
-        battle.player1
-    } else {
-        battle.player2.unwrap()
+    emit!(BattleResolved {
+        battle: ctx.accounts.battle.key(),
+        battle_id: ctx.accounts.battle.battle_id,
+        winner: settlement.winner,
+        loser: settlement.loser,
+        winner_amount: settlement.winner_amount,
+        platform_fee: settlement.platform_fee,
+        rounds: outcome.rounds,
+        seed: ctx.accounts.battle.seed,
+        randomness: ctx.accounts.battle.randomness,
     });
-    battle.resolved_at = Some(current_time);
-
-    // Update treasury
-    ctx.accounts.treasury.total_fees_collected = ctx.accounts.treasury.total_fees_collected
-        .checked_add(platform_fee)
-        .ok_or(GameError::MathOverflow)?;
-
-    msg!("Battle {} resolved. Winner: {}", 
-        battle.battle_id,
-        if winner_is_player1 { "Player 1" } else { "Player 2" }
-    );
 
     Ok(())
-}
\ No newline at end of file
+}
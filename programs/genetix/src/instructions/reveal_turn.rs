@@ -0,0 +1,370 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::Metadata,
+    token::{Mint, Token, TokenAccount},
+};
+use crate::{
+    state::*, errors::GameError, events::{BattleResolved, TurnResolved, TurnRevealed},
+    battle_engine::{self, Combatant}, settlement::{self, SettleBattle}, pokemon_lock::PokemonLock, PAUSE_BATTLES,
+};
+
+#[derive(Accounts)]
+pub struct RevealTurn<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>, // Either participant
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = !platform.is_paused(PAUSE_BATTLES) @ GameError::Paused
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, PlatformTreasury>>,
+
+    #[account(
+        mut,
+        seeds = [b"battle", battle.battle_id.to_le_bytes().as_ref()],
+        bump = battle.bump
+    )]
+    pub battle: Box<Account<'info, Battle>>,
+
+    /// CHECK: Player 1 address, must match the battle record
+    #[account(
+        mut,
+        address = battle.player1 @ GameError::NotBattleParticipant
+    )]
+    pub player1: UncheckedAccount<'info>,
+
+    /// CHECK: Player 2 address, must match the battle record
+    #[account(
+        mut,
+        constraint = battle.player2 == Some(player2.key()) @ GameError::NotBattleParticipant
+    )]
+    pub player2: UncheckedAccount<'info>,
+
+    // MON token accounts, used when this turn settles the battle
+    #[account(
+        mut,
+        address = platform.mon_token_mint
+    )]
+    pub mon_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = battle
+    )]
+    pub battle_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player1
+    )]
+    pub player1_mon_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = mon_token_mint,
+        associated_token::authority = player2
+    )]
+    pub player2_mon_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = treasury.mon_token_vault
+    )]
+    pub platform_fee_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player1_pokemon.as_ref()],
+        bump = player1_pokemon_data.bump
+    )]
+    pub player1_pokemon_data: Box<Account<'info, PokemonData>>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", battle.player2_pokemon.unwrap().as_ref()],
+        bump = player2_pokemon_data.bump
+    )]
+    pub player2_pokemon_data: Box<Account<'info, PokemonData>>,
+
+    #[account(
+        seeds = [b"type_chart"],
+        bump = type_chart.bump
+    )]
+    pub type_chart: Box<Account<'info, TypeChart>>,
+
+    // Player 1's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 1's Pokemon mint, must match the battle record
+    #[account(
+        address = battle.player1_pokemon @ GameError::InvalidPokemonMint
+    )]
+    pub player1_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player1_pokemon_mint,
+        token::authority = player1
+    )]
+    pub player1_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 1's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player1_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player1_pokemon_edition: UncheckedAccount<'info>,
+
+    // Player 2's Pokemon NFT, thawed now that the battle is over
+    /// CHECK: Player 2's Pokemon mint, must match the battle record
+    #[account(
+        constraint = battle.player2_pokemon == Some(player2_pokemon_mint.key()) @ GameError::InvalidPokemonMint
+    )]
+    pub player2_pokemon_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = player2_pokemon_mint,
+        token::authority = player2
+    )]
+    pub player2_pokemon_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Player 2's Pokemon master edition, the freeze authority of its mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            player2_pokemon_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub player2_pokemon_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reveal_turn(
+    ctx: Context<RevealTurn>,
+    action: BattleAction,
+    salt: [u8; 32],
+) -> Result<()> {
+    let battle_key = ctx.accounts.battle.key();
+    let battle: &mut Battle = &mut ctx.accounts.battle;
+    let turn = battle.turn;
+
+    require!(
+        battle.status == BattleStatus::InProgress,
+        GameError::InvalidBattleStatus
+    );
+    require!(
+        battle.mode == BattleMode::TurnBased,
+        GameError::InvalidBattleMode
+    );
+    require!(
+        battle.player1_commitment.is_some() && battle.player2_commitment.is_some(),
+        GameError::CommitmentsPending
+    );
+
+    // A late reveal would turn a stalled turn into a forfeit claim
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        battle
+            .effective_turn_deadline(&ctx.accounts.platform)
+            .is_none_or(|deadline| now <= deadline),
+        GameError::TurnDeadlinePassed
+    );
+
+    let player = ctx.accounts.player.key();
+    let is_player1 = player == battle.player1;
+    require!(
+        is_player1 || battle.player2 == Some(player),
+        GameError::NotBattleParticipant
+    );
+
+    let (commitment, revealed, revealed_salt, pokemon_data, pp) = if is_player1 {
+        (
+            battle.player1_commitment,
+            &mut battle.player1_action,
+            &mut battle.player1_salt,
+            &ctx.accounts.player1_pokemon_data,
+            &mut battle.player1_pp,
+        )
+    } else {
+        (
+            battle.player2_commitment,
+            &mut battle.player2_action,
+            &mut battle.player2_salt,
+            &ctx.accounts.player2_pokemon_data,
+            &mut battle.player2_pp,
+        )
+    };
+    require!(revealed.is_none(), GameError::AlreadyRevealed);
+    require!(
+        commitment == Some(battle_engine::action_commitment(&battle_key, turn, action, &salt)),
+        GameError::InvalidReveal
+    );
+
+    // Moves spend PP as soon as they are revealed
+    if let BattleAction::UseMove { slot } = action {
+        require!(
+            (slot as usize) < pokemon_data.moves.len(),
+            GameError::InvalidMoveSlot
+        );
+        let slot_pp = &mut pp[slot as usize];
+        require!(*slot_pp > 0, GameError::NoPpRemaining);
+        *slot_pp -= 1;
+    }
+    *revealed = Some(action);
+    *revealed_salt = Some(salt);
+    battle.record_turn_activity(&ctx.accounts.platform, now);
+
+    msg!("Turn {} revealed by {}", turn, player);
+
+    emit!(TurnRevealed {
+        battle: battle_key,
+        battle_id: battle.battle_id,
+        player,
+        turn,
+        action,
+    });
+
+    let (Some(player1_action), Some(player2_action), Some(player1_salt), Some(player2_salt)) = (
+        battle.player1_action,
+        battle.player2_action,
+        battle.player1_salt,
+        battle.player2_salt,
+    ) else {
+        return Ok(());
+    };
+
+    // Both actions are known: play out the turn with the oracle value
+    // stored when the first turn was committed and both revealed salts, so
+    // no roll can be known before both players have committed
+    let randomness = battle.randomness.ok_or(GameError::RandomnessNotReady)?;
+
+    let player1_data = &ctx.accounts.player1_pokemon_data;
+    let player2_data = &ctx.accounts.player2_pokemon_data;
+    let chosen_move = |data: &PokemonData, action: BattleAction| match action {
+        BattleAction::Attack => None,
+        BattleAction::UseMove { slot } => data.moves.get(slot as usize).copied(),
+    };
+    let player1_move = chosen_move(player1_data, player1_action);
+    let player2_move = chosen_move(player2_data, player2_action);
+
+    let [player1_hp, player2_hp] = battle_engine::resolve_turn(
+        &Combatant::from(&***player1_data),
+        &Combatant::from(&***player2_data),
+        [player1_move.as_ref(), player2_move.as_ref()],
+        [battle.player1_hp, battle.player2_hp],
+        &ctx.accounts.type_chart,
+        &battle_engine::turn_seed(
+            &battle_engine::mix_randomness(&battle.seed, &randomness),
+            turn,
+            [&player1_salt, &player2_salt],
+        ),
+    );
+
+    battle.player1_hp = player1_hp;
+    battle.player2_hp = player2_hp;
+    battle.turn = battle.turn
+        .checked_add(1)
+        .ok_or(GameError::MathOverflow)?;
+    battle.reset_turn();
+
+    msg!(
+        "Turn {} resolved (HP: {} vs {})",
+        turn,
+        player1_hp,
+        player2_hp
+    );
+
+    emit!(TurnResolved {
+        battle: battle_key,
+        battle_id: battle.battle_id,
+        turn,
+        player1_action,
+        player2_action,
+        player1_hp,
+        player2_hp,
+    });
+
+    if player1_hp > 0 && player2_hp > 0 {
+        return Ok(());
+    }
+
+    // A side fainted: pay out like resolve_battle
+    let winner_is_player1 = player1_hp > 0;
+    let rounds = u8::try_from(battle.turn).unwrap_or(u8::MAX);
+    let settlement = settlement::settle_battle(
+        SettleBattle {
+            battle: &mut ctx.accounts.battle,
+            treasury: &mut ctx.accounts.treasury,
+            battle_escrow: &ctx.accounts.battle_escrow,
+            mon_token_mint: &ctx.accounts.mon_token_mint,
+            platform_fee_account: &ctx.accounts.platform_fee_account,
+            player1_mon_account: &ctx.accounts.player1_mon_account,
+            player2_mon_account: &ctx.accounts.player2_mon_account,
+            player1_pokemon_data: &mut ctx.accounts.player1_pokemon_data,
+            player2_pokemon_data: &mut ctx.accounts.player2_pokemon_data,
+            player1_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player1_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player1_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player1_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            },
+            player2_lock: PokemonLock {
+                platform: &ctx.accounts.platform,
+                token_account: ctx.accounts.player2_pokemon_account.to_account_info(),
+                mint: ctx.accounts.player2_pokemon_mint.to_account_info(),
+                edition: ctx.accounts.player2_pokemon_edition.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            },
+            escrow_rent_destination: ctx.accounts.player1.to_account_info(),
+            token_program: &ctx.accounts.token_program,
+        },
+        winner_is_player1,
+    )?;
+
+    emit!(BattleResolved {
+        battle: battle_key,
+        battle_id: ctx.accounts.battle.battle_id,
+        winner: settlement.winner,
+        loser: settlement.loser,
+        winner_amount: settlement.winner_amount,
+        platform_fee: settlement.platform_fee,
+        rounds,
+        seed: ctx.accounts.battle.seed,
+        randomness: ctx.accounts.battle.randomness,
+    });
+
+    // Battle rent goes back to player 1, who paid for it
+    ctx.accounts.battle.close(ctx.accounts.player1.to_account_info())?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::RoleRevoked};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+}
+
+pub fn revoke_role(
+    ctx: Context<RevokeRole>,
+    member: Pubkey,
+    role: Role,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.role_registry;
+
+    let index = registry
+        .members
+        .iter()
+        .position(|entry| entry.authority == member && entry.roles & role.mask() != 0)
+        .ok_or(GameError::RoleNotAssigned)?;
+
+    registry.members[index].roles &= !role.mask();
+    if registry.members[index].roles == 0 {
+        registry.members.swap_remove(index);
+    }
+
+    msg!("Role revoked from {}", member);
+
+    emit!(RoleRevoked { member, role });
+
+    Ok(())
+}
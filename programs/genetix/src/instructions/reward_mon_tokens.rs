@@ -3,20 +3,30 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
-use crate::{state::Platform, errors::GameError};
+use crate::{state::*, errors::GameError, events::RewardIssued};
 
 #[derive(Accounts)]
 pub struct RewardMonTokens<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>, // Admin or rewards issuer
 
     #[account(
         seeds = [b"platform"],
         bump = platform.bump,
-        has_one = admin @ GameError::Unauthorized
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::RewardsIssuer
+        ) @ GameError::Unauthorized
     )]
     pub platform: Account<'info, Platform>,
 
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
     #[account(
         mut,
         address = platform.mon_token_mint
@@ -28,7 +38,7 @@ pub struct RewardMonTokens<'info> {
 
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = authority,
         associated_token::mint = mon_token_mint,
         associated_token::authority = recipient
     )]
@@ -67,5 +77,11 @@ pub fn reward_mon_tokens(
 
     msg!("Rewarded {} MON tokens to {}", amount, ctx.accounts.recipient.key());
 
+    emit!(RewardIssued {
+        authority: ctx.accounts.authority.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
     Ok(())
 }
\ No newline at end of file
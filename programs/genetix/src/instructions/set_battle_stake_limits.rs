@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::BattleStakeLimitsUpdated};
+
+#[derive(Accounts)]
+pub struct SetBattleStakeLimits<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn set_battle_stake_limits(
+    ctx: Context<SetBattleStakeLimits>,
+    min_battle_stake: u64,
+    max_battle_stake: u64,
+) -> Result<()> {
+    require!(
+        min_battle_stake > 0 && min_battle_stake <= max_battle_stake,
+        GameError::InvalidStakeLimits
+    );
+
+    let platform = &mut ctx.accounts.platform;
+    platform.min_battle_stake = min_battle_stake;
+    platform.max_battle_stake = max_battle_stake;
+
+    msg!(
+        "Battle stake limits set to {} - {} MON base units",
+        min_battle_stake,
+        max_battle_stake
+    );
+
+    emit!(BattleStakeLimitsUpdated {
+        min_battle_stake,
+        max_battle_stake,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::BattleTimeoutUpdated};
+
+#[derive(Accounts)]
+pub struct SetBattleTimeout<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn set_battle_timeout(
+    ctx: Context<SetBattleTimeout>,
+    battle_timeout_seconds: i64,
+) -> Result<()> {
+    require!(battle_timeout_seconds > 0, GameError::InvalidTimeout);
+
+    ctx.accounts.platform.battle_timeout_seconds = battle_timeout_seconds;
+
+    msg!("Battle timeout set to {} seconds", battle_timeout_seconds);
+
+    emit!(BattleTimeoutUpdated { battle_timeout_seconds });
+
+    Ok(())
+}
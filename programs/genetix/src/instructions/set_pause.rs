@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::PauseFlagsUpdated, PAUSE_BATTLES};
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn set_pause(
+    ctx: Context<SetPause>,
+    pause_flags: u8,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Track time battles spend paused so turn deadlines can be pushed back
+    let battles_paused = pause_flags & PAUSE_BATTLES != 0;
+    match (platform.battles_paused_at, battles_paused) {
+        (None, true) => platform.battles_paused_at = Some(now),
+        (Some(paused_at), false) => {
+            platform.battle_pause_seconds = platform.battle_pause_seconds
+                .checked_add(now.saturating_sub(paused_at))
+                .ok_or(GameError::MathOverflow)?;
+            platform.battles_paused_at = None;
+        }
+        _ => {}
+    }
+
+    platform.pause_flags = pause_flags;
+
+    msg!("Pause flags set to {:#010b}", pause_flags);
+
+    emit!(PauseFlagsUpdated { pause_flags });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::RandomnessOracleUpdated, randomness};
+
+#[derive(Accounts)]
+pub struct SetRandomnessOracle<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn set_randomness_oracle(
+    ctx: Context<SetRandomnessOracle>,
+    randomness_oracle: Pubkey,
+) -> Result<()> {
+    // Only oracles whose accounts load_source can parse
+    require!(
+        randomness::is_supported_oracle(&randomness_oracle),
+        GameError::InvalidRandomnessOracle
+    );
+
+    ctx.accounts.platform.randomness_oracle = randomness_oracle;
+
+    msg!("Randomness oracle set to {}", randomness_oracle);
+
+    emit!(RandomnessOracleUpdated { randomness_oracle });
+
+    Ok(())
+}
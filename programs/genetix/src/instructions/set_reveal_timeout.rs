@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{state::Platform, errors::GameError, events::RevealTimeoutUpdated};
+
+#[derive(Accounts)]
+pub struct SetRevealTimeout<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn set_reveal_timeout(
+    ctx: Context<SetRevealTimeout>,
+    reveal_timeout_seconds: i64,
+) -> Result<()> {
+    require!(reveal_timeout_seconds > 0, GameError::InvalidTimeout);
+
+    ctx.accounts.platform.reveal_timeout_seconds = reveal_timeout_seconds;
+
+    msg!("Reveal timeout set to {} seconds", reveal_timeout_seconds);
+
+    emit!(RevealTimeoutUpdated { reveal_timeout_seconds });
+
+    Ok(())
+}
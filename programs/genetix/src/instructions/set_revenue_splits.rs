@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::RevenueSplitsUpdated, MAX_REVENUE_SPLITS};
+
+#[derive(Accounts)]
+pub struct SetRevenueSplits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+}
+
+pub fn set_revenue_splits(
+    ctx: Context<SetRevenueSplits>,
+    revenue_splits: Vec<RevenueSplit>,
+) -> Result<()> {
+    require!(
+        revenue_splits.len() <= MAX_REVENUE_SPLITS,
+        GameError::InvalidRevenueSplits
+    );
+
+    if !revenue_splits.is_empty() {
+        let mut total_bps: u32 = 0;
+        for (i, split) in revenue_splits.iter().enumerate() {
+            require!(split.share_bps > 0, GameError::InvalidRevenueSplits);
+            require!(
+                revenue_splits[..i].iter().all(|other| other.recipient != split.recipient),
+                GameError::InvalidRevenueSplits
+            );
+            total_bps += split.share_bps as u32;
+        }
+        require!(total_bps == 10000, GameError::InvalidRevenueSplits);
+    }
+
+    ctx.accounts.treasury.revenue_splits = revenue_splits.clone();
+
+    msg!("Revenue splits updated: {} recipient(s)", revenue_splits.len());
+
+    emit!(RevenueSplitsUpdated { revenue_splits });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::SoulStoneBulkPricingUpdated, MAX_BULK_TIERS};
+
+#[derive(Accounts)]
+pub struct SetSoulStoneBulkPricing<'info> {
+    pub authority: Signer<'info>, // Admin or fee manager
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::FeeManager
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stone_config"],
+        bump = soul_stone_config.bump
+    )]
+    pub soul_stone_config: Account<'info, SoulStoneConfig>,
+}
+
+pub fn set_soul_stone_bulk_pricing(
+    ctx: Context<SetSoulStoneBulkPricing>,
+    bulk_tiers: Vec<BulkDiscountTier>,
+    daily_cap_per_wallet: u64,
+) -> Result<()> {
+    require!(bulk_tiers.len() <= MAX_BULK_TIERS, GameError::InvalidBulkTiers);
+    require!(
+        bulk_tiers
+            .iter()
+            .all(|tier| tier.min_quantity > 1 && tier.discount_bps < 10000),
+        GameError::InvalidBulkTiers
+    );
+    require!(
+        bulk_tiers
+            .windows(2)
+            .all(|pair| pair[0].min_quantity < pair[1].min_quantity),
+        GameError::InvalidBulkTiers
+    );
+
+    let config = &mut ctx.accounts.soul_stone_config;
+    config.bulk_tiers = bulk_tiers.clone();
+    config.daily_cap_per_wallet = daily_cap_per_wallet;
+
+    msg!(
+        "Soul Stone bulk pricing set: {} tier(s), daily cap {}",
+        bulk_tiers.len(),
+        daily_cap_per_wallet
+    );
+
+    emit!(SoulStoneBulkPricingUpdated {
+        bulk_tiers,
+        daily_cap_per_wallet,
+    });
+
+    Ok(())
+}
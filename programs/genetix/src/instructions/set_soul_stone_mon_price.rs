@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::SoulStoneMonPriceUpdated};
+
+#[derive(Accounts)]
+pub struct SetSoulStoneMonPrice<'info> {
+    pub authority: Signer<'info>, // Admin or fee manager
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::FeeManager
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"soul_stone_config"],
+        bump = soul_stone_config.bump
+    )]
+    pub soul_stone_config: Account<'info, SoulStoneConfig>,
+}
+
+pub fn set_soul_stone_mon_price(
+    ctx: Context<SetSoulStoneMonPrice>,
+    price_in_mon: Option<u64>,
+    mon_payment_mode: MonPaymentMode,
+) -> Result<()> {
+    if let Some(price) = price_in_mon {
+        require!(price > 0, GameError::InvalidPrice);
+    }
+
+    let config = &mut ctx.accounts.soul_stone_config;
+    config.price_in_mon = price_in_mon;
+    config.mon_payment_mode = mon_payment_mode;
+
+    msg!("Soul Stone MON price set to {:?}", price_in_mon);
+
+    emit!(SoulStoneMonPriceUpdated {
+        price_in_mon,
+        mon_payment_mode,
+    });
+
+    Ok(())
+}
@@ -1,19 +1,29 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::GameError};
+use crate::{state::*, errors::GameError, events::SoulStonePriceUpdated};
 
 #[derive(Accounts)]
 pub struct SetSoulStonePrice<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>, // Admin or fee manager
 
     #[account(
         mut,
         seeds = [b"platform"],
         bump = platform.bump,
-        has_one = admin @ GameError::Unauthorized
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::FeeManager
+        ) @ GameError::Unauthorized
     )]
     pub platform: Account<'info, Platform>,
 
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
     #[account(
         mut,
         seeds = [b"soul_stone_config"],
@@ -33,5 +43,7 @@ pub fn set_soul_stone_price(
 
     msg!("Soul Stone price set to {} lamports", price_in_lamports);
 
+    emit!(SoulStonePriceUpdated { price_in_lamports });
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TemplateActiveSet};
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct SetTemplateActive<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = pokemon_template.bump
+    )]
+    pub pokemon_template: Account<'info, PokemonTemplate>,
+}
+
+pub fn set_template_active(
+    ctx: Context<SetTemplateActive>,
+    template_id: u64,
+    is_active: bool,
+) -> Result<()> {
+    ctx.accounts.pokemon_template.is_active = is_active;
+
+    msg!(
+        "Pokémon template {} {}",
+        template_id,
+        if is_active { "activated" } else { "deactivated" }
+    );
+
+    emit!(TemplateActiveSet {
+        template_id,
+        is_active,
+    });
+
+    Ok(())
+}
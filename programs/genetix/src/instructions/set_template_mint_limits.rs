@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TemplateMintLimitsSet};
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct SetTemplateMintLimits<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = pokemon_template.bump
+    )]
+    pub pokemon_template: Account<'info, PokemonTemplate>,
+}
+
+pub fn set_template_mint_limits(
+    ctx: Context<SetTemplateMintLimits>,
+    template_id: u64,
+    max_supply: Option<u64>,
+    mint_start_ts: Option<i64>,
+    mint_end_ts: Option<i64>,
+) -> Result<()> {
+    let template = &mut ctx.accounts.pokemon_template;
+
+    if let Some(max_supply) = max_supply {
+        require!(
+            max_supply > 0 && max_supply >= template.times_minted,
+            GameError::InvalidSupplyCap
+        );
+    }
+    if let (Some(start), Some(end)) = (mint_start_ts, mint_end_ts) {
+        require!(start < end, GameError::InvalidMintWindow);
+    }
+
+    template.max_supply = max_supply;
+    template.mint_start_ts = mint_start_ts;
+    template.mint_end_ts = mint_end_ts;
+
+    msg!(
+        "Pokémon template {} mint limits set: max supply {:?}, window {:?} - {:?}",
+        template_id,
+        max_supply,
+        mint_start_ts,
+        mint_end_ts
+    );
+
+    emit!(TemplateMintLimitsSet {
+        template_id,
+        max_supply,
+        mint_start_ts,
+        mint_end_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TemplateTypesSet};
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct SetTemplateTypes<'info> {
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = pokemon_template.bump
+    )]
+    pub pokemon_template: Account<'info, PokemonTemplate>,
+}
+
+pub fn set_template_types(
+    ctx: Context<SetTemplateTypes>,
+    template_id: u64,
+    primary_type: ElementType,
+    secondary_type: Option<ElementType>,
+) -> Result<()> {
+    require!(
+        secondary_type != Some(primary_type),
+        GameError::InvalidElementTypes
+    );
+
+    let template = &mut ctx.accounts.pokemon_template;
+    template.primary_type = primary_type;
+    template.secondary_type = secondary_type;
+
+    msg!("Pokémon template {} types updated", template_id);
+
+    emit!(TemplateTypesSet {
+        template_id,
+        primary_type,
+        secondary_type,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TypeEffectivenessSet, MAX_TYPE_MULTIPLIER_BPS};
+
+#[derive(Accounts)]
+pub struct SetTypeEffectiveness<'info> {
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"type_chart"],
+        bump = type_chart.bump
+    )]
+    pub type_chart: Box<Account<'info, TypeChart>>,
+}
+
+pub fn set_type_effectiveness(
+    ctx: Context<SetTypeEffectiveness>,
+    attacking: ElementType,
+    defending: ElementType,
+    multiplier_bps: u16,
+) -> Result<()> {
+    require!(
+        multiplier_bps <= MAX_TYPE_MULTIPLIER_BPS,
+        GameError::InvalidTypeMultiplier
+    );
+
+    ctx.accounts
+        .type_chart
+        .set_multiplier(attacking, defending, multiplier_bps);
+
+    msg!(
+        "Type effectiveness of {:?} against {:?} set to {} bps",
+        attacking,
+        defending,
+        multiplier_bps
+    );
+
+    emit!(TypeEffectivenessSet {
+        attacking,
+        defending,
+        multiplier_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::{state::PokemonData, errors::GameError, events::PokemonOwnerSynced};
+
+#[derive(Accounts)]
+pub struct SyncPokemonOwner<'info> {
+    #[account(
+        address = pokemon_data.mint
+    )]
+    pub pokemon_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
+        bump = pokemon_data.bump
+    )]
+    pub pokemon_data: Account<'info, PokemonData>,
+
+    /// Token account currently holding the Pokemon NFT
+    #[account(
+        token::mint = pokemon_mint,
+        constraint = holder_pokemon_account.amount == 1 @ GameError::InvalidTokenHolder
+    )]
+    pub holder_pokemon_account: Account<'info, TokenAccount>,
+}
+
+pub fn sync_pokemon_owner(ctx: Context<SyncPokemonOwner>) -> Result<()> {
+    let new_owner = ctx.accounts.holder_pokemon_account.owner;
+    let pokemon_data = &mut ctx.accounts.pokemon_data;
+
+    if pokemon_data.owner == new_owner {
+        msg!("Pokemon owner already up to date: {}", new_owner);
+        return Ok(());
+    }
+
+    msg!("Pokemon owner changed from {} to {}", pokemon_data.owner, new_owner);
+
+    emit!(PokemonOwnerSynced {
+        mint: pokemon_data.mint,
+        previous_owner: pokemon_data.owner,
+        new_owner,
+    });
+
+    pokemon_data.owner = new_owner;
+
+    Ok(())
+}
@@ -1,18 +1,28 @@
 use anchor_lang::prelude::*;
-use crate::{state::Platform, errors::GameError};
+use crate::{state::*, errors::GameError, events::FeeUpdated};
 
 #[derive(Accounts)]
 pub struct UpdatePlatformFee<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>, // Admin or fee manager
 
     #[account(
         mut,
         seeds = [b"platform"],
         bump = platform.bump,
-        has_one = admin @ GameError::Unauthorized
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::FeeManager
+        ) @ GameError::Unauthorized
     )]
     pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
 }
 
 pub fn update_platform_fee(
@@ -24,9 +34,15 @@ pub fn update_platform_fee(
         GameError::InvalidFeePercentage
     );
 
+    let old_fee_percentage = ctx.accounts.platform.platform_fee_percentage;
     ctx.accounts.platform.platform_fee_percentage = new_fee_percentage;
 
     msg!("Platform fee updated to: {}%", new_fee_percentage as f64 / 100.0);
 
+    emit!(FeeUpdated {
+        old_fee_percentage,
+        new_fee_percentage,
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -2,17 +2,23 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     metadata::{
         update_metadata_accounts_v2, mpl_token_metadata::types::DataV2,
-        UpdateMetadataAccountsV2, Metadata,
+        MetadataAccount, UpdateMetadataAccountsV2, Metadata,
     },
-    token::Mint,
+    token::{Mint, TokenAccount},
 };
-use crate::{state::PokemonData, errors::GameError};
+use crate::{state::*, errors::GameError, events::PokemonMetadataUpdated};
 
 #[derive(Accounts)]
 pub struct UpdatePokemonMetadata<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump
+    )]
+    pub platform: Account<'info, Platform>,
+
     #[account(
         address = pokemon_data.mint
     )]
@@ -22,11 +28,18 @@ pub struct UpdatePokemonMetadata<'info> {
         mut,
         seeds = [b"pokemon_data", pokemon_mint.key().as_ref()],
         bump = pokemon_data.bump,
-        has_one = owner @ GameError::NotPokemonOwner
+        constraint = pokemon_data.active_battle.is_none() @ GameError::PokemonInBattle
     )]
     pub pokemon_data: Account<'info, PokemonData>,
 
-    /// CHECK: Metadata account for Pokemon NFT
+    #[account(
+        token::mint = pokemon_mint,
+        token::authority = owner,
+        constraint = owner_pokemon_account.amount == 1 @ GameError::NotPokemonOwner
+    )]
+    pub owner_pokemon_account: Account<'info, TokenAccount>,
+
+    /// Metadata account for Pokemon NFT
     #[account(
         mut,
         seeds = [
@@ -37,7 +50,7 @@ pub struct UpdatePokemonMetadata<'info> {
         bump,
         seeds::program = token_metadata_program.key(),
     )]
-    pub metadata_account: UncheckedAccount<'info>,
+    pub metadata_account: Account<'info, MetadataAccount>,
 
     pub token_metadata_program: Program<'info, Metadata>,
 }
@@ -49,14 +62,21 @@ pub fn update_pokemon_metadata(
     require!(new_uri.len() <= 200, GameError::UriTooLong);
     require!(!new_uri.is_empty(), GameError::InvalidMetadataUri);
 
-    // Update metadata URI
+    // Update metadata URI, signed by the platform PDA (update authority)
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[ctx.accounts.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
     update_metadata_accounts_v2(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             ctx.accounts.token_metadata_program.to_account_info(),
             UpdateMetadataAccountsV2 {
                 metadata: ctx.accounts.metadata_account.to_account_info(),
-                update_authority: ctx.accounts.owner.to_account_info(),
+                update_authority: ctx.accounts.platform.to_account_info(),
             },
+            signer_seeds,
         ),
         None, // new_update_authority
         Some(DataV2 {
@@ -65,7 +85,7 @@ pub fn update_pokemon_metadata(
             uri: new_uri.clone(),
             seller_fee_basis_points: 0,
             creators: None,
-            collection: None,
+            collection: ctx.accounts.metadata_account.collection.clone(), // Keep verified collection
             uses: None,
         }),
         None, // primary_sale_happened
@@ -77,5 +97,10 @@ pub fn update_pokemon_metadata(
 
     msg!("Pokemon metadata URI updated successfully");
 
+    emit!(PokemonMetadataUpdated {
+        mint: ctx.accounts.pokemon_mint.key(),
+        uri: ctx.accounts.pokemon_data.current_metadata_uri.clone(),
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::TemplateUpdated};
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct UpdatePokemonTemplate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // Admin or template curator
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        constraint = platform.has_authority(
+            &authority.key(),
+            role_registry.as_deref(),
+            Role::TemplateCurator
+        ) @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        seeds = [b"role_registry"],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [b"template", template_id.to_le_bytes().as_ref()],
+        bump = pokemon_template.bump
+    )]
+    pub pokemon_template: Account<'info, PokemonTemplate>,
+}
+
+pub fn update_pokemon_template(
+    ctx: Context<UpdatePokemonTemplate>,
+    template_id: u64,
+    name: String,
+    base_uri: String,
+    price_in_mon: u64,
+    stats: BaseStats,
+) -> Result<()> {
+    let BaseStats { hp, attack, defense, speed } = stats;
+    require!(name.len() <= 32, GameError::NameTooLong);
+    require!(base_uri.len() <= 200, GameError::UriTooLong);
+    require!(price_in_mon > 0, GameError::InvalidPrice);
+    require!(
+        hp > 0 && attack > 0 && defense > 0 && speed > 0,
+        GameError::InvalidStats
+    );
+
+    let template = &mut ctx.accounts.pokemon_template;
+    template.name = name;
+    template.base_uri = base_uri;
+    template.price_in_mon = price_in_mon;
+    template.hp = hp;
+    template.attack = attack;
+    template.defense = defense;
+    template.speed = speed;
+
+    msg!("Pokémon template updated: ID {}, Name: {}", template_id, template.name);
+
+    emit!(TemplateUpdated {
+        template_id,
+        name: template.name.clone(),
+        price_in_mon,
+        hp,
+        attack,
+        defense,
+        speed,
+    });
+
+    Ok(())
+}
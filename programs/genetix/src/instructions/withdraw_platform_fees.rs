@@ -3,7 +3,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
-use crate::{state::*, errors::GameError};
+use crate::{state::*, errors::GameError, events::FeesWithdrawn};
 
 #[derive(Accounts)]
 pub struct WithdrawPlatformFees<'info> {
@@ -33,7 +33,8 @@ pub struct WithdrawPlatformFees<'info> {
     #[account(
         mut,
         associated_token::mint = mon_token_mint,
-        associated_token::authority = platform
+        associated_token::authority = platform,
+        address = treasury.mon_token_vault
     )]
     pub platform_fee_account: Account<'info, TokenAccount>,
 
@@ -80,7 +81,19 @@ pub fn withdraw_platform_fees(
         amount,
     )?;
 
+    // Untracked deposits can push the vault above mon_balance, so saturate
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_mon_withdrawn = treasury.total_mon_withdrawn
+        .checked_add(amount)
+        .ok_or(GameError::MathOverflow)?;
+    treasury.mon_balance = treasury.mon_balance.saturating_sub(amount);
+
     msg!("Withdrew {} MON tokens from platform fees", amount);
 
+    emit!(FeesWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        amount,
+    });
+
     Ok(())
 }
\ No newline at end of file
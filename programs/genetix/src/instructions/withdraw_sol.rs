@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::GameError, events::SolWithdrawn};
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform"],
+        bump = platform.bump,
+        has_one = admin @ GameError::Unauthorized
+    )]
+    pub platform: Account<'info, Platform>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+}
+
+/// Withdraw SOL from the treasury.
+///
+/// Without revenue splits the whole amount goes to the admin. Otherwise the
+/// split recipients must be passed as writable remaining accounts in the
+/// configured order; rounding dust goes to the last recipient.
+pub fn withdraw_sol(
+    ctx: Context<WithdrawSol>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, GameError::InvalidPrice);
+
+    // Keep the treasury rent exempt
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info
+        .lamports()
+        .checked_sub(rent_exempt_minimum)
+        .ok_or(GameError::InsufficientBalance)?;
+    require!(amount <= available, GameError::InsufficientBalance);
+
+    let splits = ctx.accounts.treasury.revenue_splits.clone();
+
+    if splits.is_empty() {
+        ctx.accounts.treasury.sub_lamports(amount)?;
+        ctx.accounts.admin.add_lamports(amount)?;
+    } else {
+        require!(
+            ctx.remaining_accounts.len() == splits.len(),
+            GameError::InvalidSplitRecipient
+        );
+
+        let mut distributed: u64 = 0;
+        for (i, (split, recipient)) in splits.iter().zip(ctx.remaining_accounts.iter()).enumerate() {
+            require_keys_eq!(recipient.key(), split.recipient, GameError::InvalidSplitRecipient);
+            require!(recipient.is_writable, GameError::InvalidSplitRecipient);
+
+            let share = if i == splits.len() - 1 {
+                amount - distributed
+            } else {
+                (amount as u128)
+                    .checked_mul(split.share_bps as u128)
+                    .and_then(|x| x.checked_div(10000))
+                    .and_then(|x| u64::try_from(x).ok())
+                    .ok_or(GameError::MathOverflow)?
+            };
+
+            ctx.accounts.treasury.sub_lamports(share)?;
+            recipient.add_lamports(share)?;
+            distributed = distributed
+                .checked_add(share)
+                .ok_or(GameError::MathOverflow)?;
+
+            msg!("Sent {} lamports to {}", share, split.recipient);
+        }
+    }
+
+    ctx.accounts.treasury.total_sol_withdrawn = ctx.accounts.treasury.total_sol_withdrawn
+        .checked_add(amount)
+        .ok_or(GameError::MathOverflow)?;
+
+    msg!("Withdrew {} lamports from treasury", amount);
+
+    emit!(SolWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use crate::state::PokemonData;
+
+/// Experience awarded per resolved battle
+pub const WIN_EXPERIENCE: u64 = 100;
+pub const LOSS_EXPERIENCE: u64 = 25;
+
+/// Level cap, reached at 50 * 99^2 = 490_050 XP
+pub const MAX_LEVEL: u8 = 100;
+
+/// Cumulative XP for each level step is 50 * (level - 1)^2
+const EXPERIENCE_CURVE_FACTOR: u64 = 50;
+
+/// Flat stat gains per level above 1
+const HP_PER_LEVEL: u16 = 3;
+const ATTACK_PER_LEVEL: u16 = 2;
+const DEFENSE_PER_LEVEL: u16 = 2;
+const SPEED_PER_LEVEL: u16 = 1;
+
+/// Total experience needed to reach `level`
+pub fn experience_for_level(level: u8) -> u64 {
+    let steps = level.saturating_sub(1) as u64;
+    EXPERIENCE_CURVE_FACTOR * steps * steps
+}
+
+/// Highest level reachable with `experience`, capped at `MAX_LEVEL`
+pub fn level_for_experience(experience: u64) -> u8 {
+    let mut level = 1;
+    while level < MAX_LEVEL && experience >= experience_for_level(level + 1) {
+        level += 1;
+    }
+    level
+}
+
+/// Stat bonus accumulated by reaching `level`, as (hp, attack, defense, speed)
+pub fn level_bonus(level: u8) -> (u16, u16, u16, u16) {
+    let levels = level.saturating_sub(1) as u16;
+    (
+        HP_PER_LEVEL * levels,
+        ATTACK_PER_LEVEL * levels,
+        DEFENSE_PER_LEVEL * levels,
+        SPEED_PER_LEVEL * levels,
+    )
+}
+
+/// Add experience and apply any level-ups to the Pokémon's stats.
+///
+/// Returns the number of levels gained.
+pub fn award_experience(pokemon: &mut PokemonData, experience: u64) -> u8 {
+    pokemon.experience = pokemon.experience.saturating_add(experience);
+
+    let new_level = level_for_experience(pokemon.experience).max(pokemon.level);
+    let levels_gained = new_level - pokemon.level;
+    if levels_gained == 0 {
+        return 0;
+    }
+
+    let (old_hp, old_attack, old_defense, old_speed) = level_bonus(pokemon.level);
+    let (new_hp, new_attack, new_defense, new_speed) = level_bonus(new_level);
+    pokemon.hp = pokemon.hp.saturating_add(new_hp - old_hp);
+    pokemon.attack = pokemon.attack.saturating_add(new_attack - old_attack);
+    pokemon.defense = pokemon.defense.saturating_add(new_defense - old_defense);
+    pokemon.speed = pokemon.speed.saturating_add(new_speed - old_speed);
+    pokemon.level = new_level;
+
+    levels_gained
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use crate::state::ElementType;
+
+    fn pokemon(level: u8, experience: u64, base: (u16, u16, u16, u16)) -> PokemonData {
+        let (hp, attack, defense, speed) = level_bonus(level);
+        PokemonData {
+            owner: Pubkey::default(),
+            mint: Pubkey::default(),
+            template_id: 0,
+            name: String::new(),
+            current_metadata_uri: String::new(),
+            hp: base.0 + hp,
+            attack: base.1 + attack,
+            defense: base.2 + defense,
+            speed: base.3 + speed,
+            primary_type: ElementType::Normal,
+            secondary_type: None,
+            level,
+            experience,
+            moves: Vec::new(),
+            evolution_stage: 0,
+            battles_won: 0,
+            battles_lost: 0,
+            created_at: 0,
+            last_battle_at: 0,
+            active_battle: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn levels_at_curve_thresholds() {
+        assert_eq!(level_for_experience(0), 1);
+        assert_eq!(level_for_experience(49), 1);
+        assert_eq!(level_for_experience(50), 2);
+        assert_eq!(level_for_experience(199), 2);
+        assert_eq!(level_for_experience(200), 3);
+        assert_eq!(level_for_experience(490_049), MAX_LEVEL - 1);
+        assert_eq!(level_for_experience(490_050), MAX_LEVEL);
+        assert_eq!(level_for_experience(u64::MAX), MAX_LEVEL);
+        assert_eq!(experience_for_level(MAX_LEVEL), 490_050);
+    }
+
+    #[test]
+    fn evolved_pokemon_only_gains_the_bonus_difference() {
+        // evolve_pokemon sets stats to the rule's base plus the current level bonus
+        let base = (80, 60, 50, 40);
+        let mut evolved = pokemon(5, experience_for_level(5), base);
+
+        let gained = award_experience(&mut evolved, experience_for_level(7) - experience_for_level(5));
+
+        let expected = pokemon(7, experience_for_level(7), base);
+        assert_eq!(gained, 2);
+        assert_eq!(evolved.level, 7);
+        assert_eq!(
+            (evolved.hp, evolved.attack, evolved.defense, evolved.speed),
+            (expected.hp, expected.attack, expected.defense, expected.speed)
+        );
+    }
+
+    #[test]
+    fn level_never_drops_below_current() {
+        let mut pokemon = pokemon(10, 0, (50, 50, 50, 50));
+        let stats = (pokemon.hp, pokemon.attack, pokemon.defense, pokemon.speed);
+
+        assert_eq!(award_experience(&mut pokemon, WIN_EXPERIENCE), 0);
+        assert_eq!(pokemon.level, 10);
+        assert_eq!((pokemon.hp, pokemon.attack, pokemon.defense, pokemon.speed), stats);
+    }
+
+    #[test]
+    fn no_level_gained_at_max_experience() {
+        let mut pokemon = pokemon(MAX_LEVEL, u64::MAX, (50, 50, 50, 50));
+        let stats = (pokemon.hp, pokemon.attack, pokemon.defense, pokemon.speed);
+
+        assert_eq!(award_experience(&mut pokemon, WIN_EXPERIENCE), 0);
+        assert_eq!(pokemon.experience, u64::MAX);
+        assert_eq!(pokemon.level, MAX_LEVEL);
+        assert_eq!((pokemon.hp, pokemon.attack, pokemon.defense, pokemon.speed), stats);
+    }
+}
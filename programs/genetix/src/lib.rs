@@ -3,16 +3,47 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod events;
+pub mod battle_engine;
+pub mod leveling;
+pub mod settlement;
+pub mod randomness;
+pub mod pokemon_lock;
+pub mod soul_stones;
 
 pub use instructions::*;
 pub use state::*;
 pub use errors::*;
+pub use events::*;
 
 declare_id!("5a9hz3a3PnFfLzc5tzPChZKCSnJTdf1oTLmXzTGaRryv");
 
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const MON_TOKEN_DECIMALS: u8 = 9;
 pub const SOUL_STONE_DECIMALS: u8 = 0;
+pub const MAX_RESOLVERS: usize = 10;
+pub const MAX_ROLE_MEMBERS: usize = 16;
+pub const MAX_REVENUE_SPLITS: usize = 5;
+pub const MAX_BULK_TIERS: usize = 5;
+pub const MAX_MOVES_PER_POKEMON: usize = 4;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const ELEMENT_TYPE_COUNT: usize = 18;
+pub const TYPE_CHART_SIZE: usize = ELEMENT_TYPE_COUNT * ELEMENT_TYPE_COUNT;
+pub const TYPE_NEUTRAL_BPS: u16 = 10_000; // 1x damage
+pub const MAX_TYPE_MULTIPLIER_BPS: u16 = 40_000; // 4x damage
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+pub const SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID: Pubkey = pubkey!("Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2");
+
+// Pause flags (Platform.pause_flags)
+pub const PAUSE_MINTING: u8 = 1 << 0;
+pub const PAUSE_SOUL_STONES: u8 = 1 << 1;
+pub const PAUSE_EVOLUTION: u8 = 1 << 2;
+pub const PAUSE_BATTLES: u8 = 1 << 3;
+pub const PAUSE_ALL: u8 = u8::MAX;
+pub const DEFAULT_BATTLE_TIMEOUT_SECONDS: i64 = 86_400; // 24 hours
+pub const DEFAULT_REVEAL_TIMEOUT_SECONDS: i64 = 600; // 10 minutes
+pub const DEFAULT_MIN_BATTLE_STAKE: u64 = 1_000_000_000; // 1 MON token
+pub const DEFAULT_MAX_BATTLE_STAKE: u64 = 1_000_000_000_000; // 1,000 MON tokens
 
 #[program]
 pub mod pokemon_game {
@@ -28,6 +59,37 @@ pub mod pokemon_game {
         instructions::initialize_platform::initialize_platform(ctx, platform_fee_percentage)
     }
 
+    /// Propose a new admin; takes effect once they call accept_admin
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    /// Accept a pending admin handover
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    /// Grant a delegated admin role to an account
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        member: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, member, role)
+    }
+
+    /// Revoke a delegated admin role from an account
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        member: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx, member, role)
+    }
+
     /// Create the MON token (ERC20-like utility token)
     pub fn create_mon_token(
         ctx: Context<CreateMonToken>,
@@ -48,6 +110,27 @@ pub mod pokemon_game {
         instructions::create_soul_stone_token::create_soul_stone_token(ctx, token_name, token_symbol, token_uri)
     }
 
+    /// Create the verified Genetix collection NFT owned by the platform
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_collection::create_collection(ctx, name, symbol, uri)
+    }
+
+    /// Create a per-template sub-collection inside the Genetix collection
+    pub fn create_template_collection(
+        ctx: Context<CreateTemplateCollection>,
+        template_id: u64,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_template_collection::create_template_collection(ctx, template_id, name, symbol, uri)
+    }
+
     /// Set the price for minting Soul Stones in SOL
     pub fn set_soul_stone_price(
         ctx: Context<SetSoulStonePrice>,
@@ -56,6 +139,24 @@ pub mod pokemon_game {
         instructions::set_soul_stone_price::set_soul_stone_price(ctx, price_in_lamports)
     }
 
+    /// Configure Soul Stone bulk discount tiers and the per-wallet daily cap
+    pub fn set_soul_stone_bulk_pricing(
+        ctx: Context<SetSoulStoneBulkPricing>,
+        bulk_tiers: Vec<BulkDiscountTier>,
+        daily_cap_per_wallet: u64,
+    ) -> Result<()> {
+        instructions::set_soul_stone_bulk_pricing::set_soul_stone_bulk_pricing(ctx, bulk_tiers, daily_cap_per_wallet)
+    }
+
+    /// Set the MON price for Soul Stones and whether paid MON is burned or kept
+    pub fn set_soul_stone_mon_price(
+        ctx: Context<SetSoulStoneMonPrice>,
+        price_in_mon: Option<u64>,
+        mon_payment_mode: MonPaymentMode,
+    ) -> Result<()> {
+        instructions::set_soul_stone_mon_price::set_soul_stone_mon_price(ctx, price_in_mon, mon_payment_mode)
+    }
+
     /// Add a Pokémon template to the marketplace
     pub fn add_pokemon_template(
         ctx: Context<AddPokemonTemplate>,
@@ -63,10 +164,7 @@ pub mod pokemon_game {
         name: String,
         base_uri: String,
         price_in_mon: u64,
-        hp: u16,
-        attack: u16,
-        defense: u16,
-        speed: u16,
+        stats: BaseStats,
     ) -> Result<()> {
         instructions::add_pokemon_template::add_pokemon_template(
             ctx,
@@ -74,10 +172,127 @@ pub mod pokemon_game {
             name,
             base_uri,
             price_in_mon,
-            hp,
-            attack,
-            defense,
-            speed,
+            stats,
+        )
+    }
+
+    /// Update a Pokémon template's name, URI, price and stats
+    pub fn update_pokemon_template(
+        ctx: Context<UpdatePokemonTemplate>,
+        template_id: u64,
+        name: String,
+        base_uri: String,
+        price_in_mon: u64,
+        stats: BaseStats,
+    ) -> Result<()> {
+        instructions::update_pokemon_template::update_pokemon_template(
+            ctx,
+            template_id,
+            name,
+            base_uri,
+            price_in_mon,
+            stats,
+        )
+    }
+
+    /// Activate or deactivate a Pokémon template for minting
+    pub fn set_template_active(
+        ctx: Context<SetTemplateActive>,
+        template_id: u64,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::set_template_active::set_template_active(ctx, template_id, is_active)
+    }
+
+    /// Set optional supply cap and mint window for a Pokémon template
+    pub fn set_template_mint_limits(
+        ctx: Context<SetTemplateMintLimits>,
+        template_id: u64,
+        max_supply: Option<u64>,
+        mint_start_ts: Option<i64>,
+        mint_end_ts: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_template_mint_limits::set_template_mint_limits(
+            ctx,
+            template_id,
+            max_supply,
+            mint_start_ts,
+            mint_end_ts,
+        )
+    }
+
+    /// Set the elemental types of a Pokémon template
+    pub fn set_template_types(
+        ctx: Context<SetTemplateTypes>,
+        template_id: u64,
+        primary_type: ElementType,
+        secondary_type: Option<ElementType>,
+    ) -> Result<()> {
+        instructions::set_template_types::set_template_types(
+            ctx,
+            template_id,
+            primary_type,
+            secondary_type,
+        )
+    }
+
+    /// Create the type effectiveness chart with every matchup neutral
+    pub fn initialize_type_chart(ctx: Context<InitializeTypeChart>) -> Result<()> {
+        instructions::initialize_type_chart::initialize_type_chart(ctx)
+    }
+
+    /// Set the damage multiplier for one attacking/defending type matchup
+    pub fn set_type_effectiveness(
+        ctx: Context<SetTypeEffectiveness>,
+        attacking: ElementType,
+        defending: ElementType,
+        multiplier_bps: u16,
+    ) -> Result<()> {
+        instructions::set_type_effectiveness::set_type_effectiveness(
+            ctx,
+            attacking,
+            defending,
+            multiplier_bps,
+        )
+    }
+
+    /// Define the stats, URI and Soul Stone cost of a template's evolution stage
+    pub fn add_evolution_rule(
+        ctx: Context<AddEvolutionRule>,
+        template_id: u64,
+        evolution_stage: u8,
+        metadata_uri: String,
+        stats: BaseStats,
+        soul_stone_cost: u64,
+        min_level: u8,
+    ) -> Result<()> {
+        instructions::add_evolution_rule::add_evolution_rule(
+            ctx,
+            template_id,
+            evolution_stage,
+            metadata_uri,
+            stats,
+            soul_stone_cost,
+            min_level,
+        )
+    }
+
+    /// Add a move to the move catalog
+    pub fn add_move(
+        ctx: Context<AddMove>,
+        move_id: u64,
+        name: String,
+        stats: MoveStats,
+        min_level: u8,
+        template_id: Option<u64>,
+    ) -> Result<()> {
+        instructions::add_move::add_move(
+            ctx,
+            move_id,
+            name,
+            stats,
+            min_level,
+            template_id,
         )
     }
 
@@ -89,6 +304,89 @@ pub mod pokemon_game {
         instructions::update_platform_fee::update_platform_fee(ctx, new_fee_percentage)
     }
 
+    /// Update how long an in-progress battle may stay unresolved
+    pub fn set_battle_timeout(
+        ctx: Context<SetBattleTimeout>,
+        battle_timeout_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_battle_timeout::set_battle_timeout(ctx, battle_timeout_seconds)
+    }
+
+    /// Update how long a player has to commit or reveal once their opponent has
+    pub fn set_reveal_timeout(
+        ctx: Context<SetRevealTimeout>,
+        reveal_timeout_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_reveal_timeout::set_reveal_timeout(ctx, reveal_timeout_seconds)
+    }
+
+    /// Set the Switchboard On-Demand program whose randomness accounts supply
+    /// battle randomness
+    pub fn set_randomness_oracle(
+        ctx: Context<SetRandomnessOracle>,
+        randomness_oracle: Pubkey,
+    ) -> Result<()> {
+        instructions::set_randomness_oracle::set_randomness_oracle(ctx, randomness_oracle)
+    }
+
+    /// Reveal a local randomness request (stand-in for Switchboard)
+    #[cfg(feature = "localnet")]
+    pub fn fulfill_local_randomness(
+        ctx: Context<FulfillLocalRandomness>,
+        value: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_local_randomness::fulfill_local_randomness(ctx, value)
+    }
+
+    /// Update the minimum and maximum MON stake allowed per battle
+    pub fn set_battle_stake_limits(
+        ctx: Context<SetBattleStakeLimits>,
+        min_battle_stake: u64,
+        max_battle_stake: u64,
+    ) -> Result<()> {
+        instructions::set_battle_stake_limits::set_battle_stake_limits(ctx, min_battle_stake, max_battle_stake)
+    }
+
+    /// Pause or unpause platform features (see PAUSE_* flags)
+    pub fn set_pause(
+        ctx: Context<SetPause>,
+        pause_flags: u8,
+    ) -> Result<()> {
+        instructions::set_pause::set_pause(ctx, pause_flags)
+    }
+
+    /// Register an oracle that is allowed to resolve battles
+    pub fn add_resolver(
+        ctx: Context<AddResolver>,
+        resolver: Pubkey,
+    ) -> Result<()> {
+        instructions::add_resolver::add_resolver(ctx, resolver)
+    }
+
+    /// Remove an oracle from the resolver registry
+    pub fn remove_resolver(
+        ctx: Context<RemoveResolver>,
+        resolver: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_resolver::remove_resolver(ctx, resolver)
+    }
+
+    /// Configure how withdrawn SOL is split between recipients
+    pub fn set_revenue_splits(
+        ctx: Context<SetRevenueSplits>,
+        revenue_splits: Vec<RevenueSplit>,
+    ) -> Result<()> {
+        instructions::set_revenue_splits::set_revenue_splits(ctx, revenue_splits)
+    }
+
+    /// Withdraw Soul Stone SOL revenue from the treasury (admin only)
+    pub fn withdraw_sol(
+        ctx: Context<WithdrawSol>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_sol::withdraw_sol(ctx, amount)
+    }
+
     // ============ USER INSTRUCTIONS ============
 
     /// Mint a new Pokémon NFT from a template
@@ -99,40 +397,64 @@ pub mod pokemon_game {
         instructions::mint_pokemon::mint_pokemon(ctx, template_id)
     }
 
-    /// Mint a Soul Stone by paying SOL
-    pub fn mint_soul_stone(ctx: Context<MintSoulStone>) -> Result<()> {
-        instructions::mint_soul_stone::mint_soul_stone(ctx)
+    /// Mint Soul Stones by paying SOL, with bulk discounts
+    pub fn mint_soul_stone(
+        ctx: Context<MintSoulStone>,
+        quantity: u64,
+    ) -> Result<()> {
+        instructions::mint_soul_stone::mint_soul_stone(ctx, quantity)
     }
 
-    /// Evolve a Pokémon by burning a Soul Stone
-    pub fn evolve_pokemon(
-        ctx: Context<EvolvePokemon>,
-        new_metadata_uri: String,
-        new_hp: u16,
-        new_attack: u16,
-        new_defense: u16,
-        new_speed: u16,
+    /// Mint Soul Stones by paying MON, with bulk discounts
+    pub fn mint_soul_stone_with_mon(
+        ctx: Context<MintSoulStoneWithMon>,
+        quantity: u64,
     ) -> Result<()> {
-        instructions::evolve_pokemon::evolve_pokemon(
-            ctx,
-            new_metadata_uri,
-            new_hp,
-            new_attack,
-            new_defense,
-            new_speed,
-        )
+        instructions::mint_soul_stone_with_mon::mint_soul_stone_with_mon(ctx, quantity)
+    }
+
+    /// Evolve a Pokémon to its next stage by burning Soul Stones
+    pub fn evolve_pokemon(ctx: Context<EvolvePokemon>) -> Result<()> {
+        instructions::evolve_pokemon::evolve_pokemon(ctx)
     }
 
-    /// Create a PvP battle by staking MON tokens
+    /// Teach a Pokémon a move from the catalog into one of its four slots
+    pub fn learn_move(
+        ctx: Context<LearnMove>,
+        slot: u8,
+    ) -> Result<()> {
+        instructions::learn_move::learn_move(ctx, slot)
+    }
+
+    /// Create a PvP battle by staking MON tokens and locking a Pokemon into it
     pub fn create_battle(
         ctx: Context<CreateBattle>,
         battle_id: u64,
         pokemon_mint: Pubkey,
+        stake_amount: u64,
+        mode: BattleMode,
+        terms: ChallengeTerms,
+    ) -> Result<()> {
+        instructions::create_battle::create_battle(
+            ctx,
+            battle_id,
+            pokemon_mint,
+            stake_amount,
+            mode,
+            terms,
+        )
+    }
+
+    /// Open a local randomness request (stand-in for Switchboard)
+    #[cfg(feature = "localnet")]
+    pub fn request_local_randomness(
+        ctx: Context<RequestLocalRandomness>,
+        randomness_id: u64,
     ) -> Result<()> {
-        instructions::create_battle::create_battle(ctx, battle_id, pokemon_mint)
+        instructions::request_local_randomness::request_local_randomness(ctx, randomness_id)
     }
 
-    /// Join an existing battle by staking MON tokens
+    /// Join an existing battle by staking MON tokens and locking a Pokemon into it
     pub fn join_battle(
         ctx: Context<JoinBattle>,
         pokemon_mint: Pubkey,
@@ -140,12 +462,41 @@ pub mod pokemon_game {
         instructions::join_battle::join_battle(ctx, pokemon_mint)
     }
 
-    /// Resolve a battle and distribute rewards
-    pub fn resolve_battle(
-        ctx: Context<ResolveBattle>,
-        winner_is_player1: bool,
+    /// Cancel a battle nobody has joined and reclaim the stake
+    pub fn cancel_battle(ctx: Context<CancelBattle>) -> Result<()> {
+        instructions::cancel_battle::cancel_battle(ctx)
+    }
+
+    /// Refund both players of a battle left unresolved past the deadline
+    pub fn expire_battle(ctx: Context<ExpireBattle>) -> Result<()> {
+        instructions::expire_battle::expire_battle(ctx)
+    }
+
+    /// Resolve a battle by simulating it on-chain and distribute rewards
+    pub fn resolve_battle(ctx: Context<ResolveBattle>) -> Result<()> {
+        instructions::resolve_battle::resolve_battle(ctx)
+    }
+
+    /// Commit to a hidden action for the current turn of a turn-based battle
+    pub fn commit_turn(
+        ctx: Context<CommitTurn>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_turn::commit_turn(ctx, commitment)
+    }
+
+    /// Reveal a committed action, playing out the turn once both are revealed
+    pub fn reveal_turn(
+        ctx: Context<RevealTurn>,
+        action: BattleAction,
+        salt: [u8; 32],
     ) -> Result<()> {
-        instructions::resolve_battle::resolve_battle(ctx, winner_is_player1)
+        instructions::reveal_turn::reveal_turn(ctx, action, salt)
+    }
+
+    /// Win a turn-based battle whose opponent missed the commit or reveal deadline
+    pub fn claim_forfeit(ctx: Context<ClaimForfeit>) -> Result<()> {
+        instructions::claim_forfeit::claim_forfeit(ctx)
     }
 
     /// Reward MON tokens to a player (for winning battles/defeating bosses)
@@ -164,6 +515,11 @@ pub mod pokemon_game {
         instructions::update_pokemon_metadata::update_pokemon_metadata(ctx, new_uri)
     }
 
+    /// Sync PokemonData.owner with the current NFT holder (permissionless)
+    pub fn sync_pokemon_owner(ctx: Context<SyncPokemonOwner>) -> Result<()> {
+        instructions::sync_pokemon_owner::sync_pokemon_owner(ctx)
+    }
+
     /// Withdraw platform fees (admin only)
     pub fn withdraw_platform_fees(
         ctx: Context<WithdrawPlatformFees>,
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{
+        freeze_delegated_account, thaw_delegated_account, FreezeDelegatedAccount,
+        ThawDelegatedAccount,
+    },
+    token::{approve, Approve},
+};
+use crate::{state::*, errors::GameError};
+
+/// Accounts needed to freeze or thaw a Pokémon NFT held in a battle
+/// participant's token account. The platform PDA is the freeze delegate.
+pub struct PokemonLock<'a, 'info> {
+    pub platform: &'a Account<'info, Platform>,
+    pub token_account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub edition: AccountInfo<'info>, // Master edition, the mint's freeze authority
+    pub token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Lock a Pokémon into `battle`: it can no longer join other battles,
+/// evolve, learn moves or change metadata, and its NFT is frozen so it
+/// cannot be transferred until the battle ends.
+pub fn lock_pokemon<'info>(
+    lock: PokemonLock<'_, 'info>,
+    owner: AccountInfo<'info>,
+    pokemon_data: &mut PokemonData,
+    battle: Pubkey,
+) -> Result<()> {
+    require!(
+        pokemon_data.active_battle.is_none(),
+        GameError::PokemonInBattle
+    );
+
+    // Owner delegates the NFT to the platform so the platform can freeze it
+    approve(
+        CpiContext::new(
+            lock.token_program.clone(),
+            Approve {
+                to: lock.token_account.clone(),
+                delegate: lock.platform.to_account_info(),
+                authority: owner,
+            },
+        ),
+        1,
+    )?;
+
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[lock.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
+    freeze_delegated_account(CpiContext::new_with_signer(
+        lock.token_metadata_program.clone(),
+        FreezeDelegatedAccount {
+            metadata: lock.token_metadata_program,
+            delegate: lock.platform.to_account_info(),
+            token_account: lock.token_account,
+            edition: lock.edition,
+            mint: lock.mint,
+            token_program: lock.token_program,
+        },
+        signer_seeds,
+    ))?;
+
+    pokemon_data.active_battle = Some(battle);
+
+    Ok(())
+}
+
+/// Release a Pokémon from its battle and thaw its NFT.
+///
+/// The platform stays approved as delegate until the owner revokes it or
+/// the NFT next moves, but it only ever signs to freeze and thaw.
+pub fn unlock_pokemon(lock: PokemonLock, pokemon_data: &mut PokemonData) -> Result<()> {
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[lock.platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
+    thaw_delegated_account(CpiContext::new_with_signer(
+        lock.token_metadata_program.clone(),
+        ThawDelegatedAccount {
+            metadata: lock.token_metadata_program,
+            delegate: lock.platform.to_account_info(),
+            token_account: lock.token_account,
+            edition: lock.edition,
+            mint: lock.mint,
+            token_program: lock.token_program,
+        },
+        signer_seeds,
+    ))?;
+
+    pokemon_data.active_battle = None;
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use crate::{
+    errors::GameError, SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID, SWITCHBOARD_ON_DEMAND_PROGRAM_ID,
+};
+#[cfg(feature = "localnet")]
+use crate::state::LocalRandomness;
+
+/// A source of 32 bytes of randomness that nobody in the battle can bias
+pub trait RandomnessSource {
+    /// Slot the current request was opened at
+    fn request_slot(&self) -> u64;
+    /// The fulfilled value, or `None` while the request is still pending
+    fn revealed_value(&self) -> Option<[u8; 32]>;
+}
+
+/// Switchboard On-Demand `RandomnessAccountData`, read from raw account data.
+///
+/// The request is opened against the slot hash of `seed_slot` and revealed by
+/// the oracle at `reveal_slot`. The account's authority can reopen it at any
+/// time, so a value only answers the request whose `seed_slot` it was pinned to.
+pub struct SwitchboardRandomness {
+    pub seed_slot: u64,
+    pub reveal_slot: u64,
+    pub value: [u8; 32],
+}
+
+impl SwitchboardRandomness {
+    /// sha256("account:RandomnessAccountData")[..8]
+    pub const DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+    // Discriminator, authority, queue, seed_slothash
+    const SEED_SLOT_OFFSET: usize = 8 + 32 + 32 + 32;
+    // seed_slot, oracle
+    const REVEAL_SLOT_OFFSET: usize = Self::SEED_SLOT_OFFSET + 8 + 32;
+    const VALUE_OFFSET: usize = Self::REVEAL_SLOT_OFFSET + 8;
+    // value, then two reserved buffers
+    pub const LEN: usize = Self::VALUE_OFFSET + 32 + 96 + 128;
+
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= Self::LEN && data[..8] == Self::DISCRIMINATOR,
+            GameError::InvalidRandomnessAccount
+        );
+
+        let read_slot = |offset: usize| {
+            let mut slot = [0u8; 8];
+            slot.copy_from_slice(&data[offset..offset + 8]);
+            u64::from_le_bytes(slot)
+        };
+        let mut value = [0u8; 32];
+        value.copy_from_slice(&data[Self::VALUE_OFFSET..Self::VALUE_OFFSET + 32]);
+
+        Ok(Self {
+            seed_slot: read_slot(Self::SEED_SLOT_OFFSET),
+            reveal_slot: read_slot(Self::REVEAL_SLOT_OFFSET),
+            value,
+        })
+    }
+}
+
+impl RandomnessSource for SwitchboardRandomness {
+    fn request_slot(&self) -> u64 {
+        self.seed_slot
+    }
+
+    // A reveal left over from an earlier request sits at or before the new
+    // seed slot
+    fn revealed_value(&self) -> Option<[u8; 32]> {
+        (self.reveal_slot > self.seed_slot).then_some(self.value)
+    }
+}
+
+#[cfg(feature = "localnet")]
+impl RandomnessSource for LocalRandomness {
+    fn request_slot(&self) -> u64 {
+        self.requested_slot
+    }
+
+    fn revealed_value(&self) -> Option<[u8; 32]> {
+        self.revealed.then_some(self.value)
+    }
+}
+
+/// Whether `set_randomness_oracle` may point at this program. Only
+/// Switchboard On-Demand is supported, plus this program on localnet builds
+pub fn is_supported_oracle(randomness_oracle: &Pubkey) -> bool {
+    *randomness_oracle == SWITCHBOARD_ON_DEMAND_PROGRAM_ID
+        || *randomness_oracle == SWITCHBOARD_ON_DEMAND_DEVNET_PROGRAM_ID
+        || (cfg!(feature = "localnet") && *randomness_oracle == crate::ID)
+}
+
+/// Load a randomness account owned by the platform's configured oracle.
+///
+/// With the `localnet` feature, an oracle of this program means
+/// `LocalRandomness` accounts stand in for Switchboard.
+pub fn load_source(
+    account: &AccountInfo,
+    randomness_oracle: &Pubkey,
+) -> Result<Box<dyn RandomnessSource>> {
+    // The default key is the system program, so it must never act as oracle
+    require!(
+        *randomness_oracle != Pubkey::default(),
+        GameError::RandomnessOracleNotSet
+    );
+    require_keys_eq!(
+        *account.owner,
+        *randomness_oracle,
+        GameError::InvalidRandomnessAccount
+    );
+
+    let data = account.try_borrow_data()?;
+    #[cfg(feature = "localnet")]
+    if *randomness_oracle == crate::ID {
+        let local = LocalRandomness::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(GameError::InvalidRandomnessAccount))?;
+        return Ok(Box::new(local));
+    }
+    require!(
+        is_supported_oracle(randomness_oracle),
+        GameError::InvalidRandomnessOracle
+    );
+    Ok(Box::new(SwitchboardRandomness::try_from_data(&data)?))
+}
+
+/// Check a randomness request is safe to commit a battle to: seeded at most
+/// one slot ago and not yet revealed. Returns the slot to pin the request to
+pub fn pending_request_slot(account: &AccountInfo, randomness_oracle: &Pubkey) -> Result<u64> {
+    let source = load_source(account, randomness_oracle)?;
+    require!(
+        source.revealed_value().is_none(),
+        GameError::RandomnessAlreadyRevealed
+    );
+    require!(
+        Clock::get()?.slot.saturating_sub(source.request_slot()) <= 1,
+        GameError::RandomnessRequestStale
+    );
+    Ok(source.request_slot())
+}
+
+/// Revealed value of the request pinned at `request_slot`, failing while it
+/// is pending or if the account has since been reopened
+pub fn revealed_value(
+    account: &AccountInfo,
+    randomness_oracle: &Pubkey,
+    request_slot: u64,
+) -> Result<[u8; 32]> {
+    let source = load_source(account, randomness_oracle)?;
+    require!(
+        source.request_slot() == request_slot,
+        GameError::RandomnessRequestStale
+    );
+    source
+        .revealed_value()
+        .ok_or_else(|| error!(GameError::RandomnessNotReady))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_data(seed_slot: u64, reveal_slot: u64, value: [u8; 32]) -> Vec<u8> {
+        let mut data = vec![0u8; SwitchboardRandomness::LEN];
+        data[..8].copy_from_slice(&SwitchboardRandomness::DISCRIMINATOR);
+        data[104..112].copy_from_slice(&seed_slot.to_le_bytes());
+        data[144..152].copy_from_slice(&reveal_slot.to_le_bytes());
+        data[152..184].copy_from_slice(&value);
+        data
+    }
+
+    #[test]
+    fn account_len_matches_switchboard() {
+        assert_eq!(SwitchboardRandomness::LEN, 408);
+    }
+
+    #[test]
+    fn parses_slots_and_value() {
+        let parsed = SwitchboardRandomness::try_from_data(&account_data(100, 102, [7; 32])).unwrap();
+        assert_eq!(parsed.seed_slot, 100);
+        assert_eq!(parsed.reveal_slot, 102);
+        assert_eq!(parsed.value, [7; 32]);
+        assert_eq!(parsed.request_slot(), 100);
+        assert_eq!(parsed.revealed_value(), Some([7; 32]));
+    }
+
+    #[test]
+    fn rejects_other_accounts() {
+        let mut data = account_data(100, 102, [7; 32]);
+        data[0] ^= 1;
+        assert!(SwitchboardRandomness::try_from_data(&data).is_err());
+
+        let data = account_data(100, 102, [7; 32]);
+        assert!(SwitchboardRandomness::try_from_data(&data[..SwitchboardRandomness::LEN - 1]).is_err());
+    }
+
+    #[test]
+    fn pending_until_revealed_after_seed_slot() {
+        let pending = SwitchboardRandomness::try_from_data(&account_data(100, 0, [0; 32])).unwrap();
+        assert_eq!(pending.revealed_value(), None);
+
+        // Reopened after an earlier reveal: the old value must not be used
+        let reopened = SwitchboardRandomness::try_from_data(&account_data(100, 90, [7; 32])).unwrap();
+        assert_eq!(reopened.revealed_value(), None);
+    }
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    close_account, transfer_checked, CloseAccount, Mint, Token, TokenAccount, TransferChecked,
+};
+use crate::{
+    state::*, errors::GameError, events::PokemonLeveledUp, leveling,
+    pokemon_lock::{self, PokemonLock},
+};
+
+/// Accounts touched when paying out a finished battle
+pub struct SettleBattle<'a, 'info> {
+    pub battle: &'a mut Account<'info, Battle>,
+    pub treasury: &'a mut Account<'info, PlatformTreasury>,
+    pub battle_escrow: &'a Account<'info, TokenAccount>,
+    pub mon_token_mint: &'a Account<'info, Mint>,
+    pub platform_fee_account: &'a Account<'info, TokenAccount>,
+    pub player1_mon_account: &'a Account<'info, TokenAccount>,
+    pub player2_mon_account: &'a Account<'info, TokenAccount>,
+    pub player1_pokemon_data: &'a mut Account<'info, PokemonData>,
+    pub player2_pokemon_data: &'a mut Account<'info, PokemonData>,
+    pub player1_lock: PokemonLock<'a, 'info>,
+    pub player2_lock: PokemonLock<'a, 'info>,
+    pub escrow_rent_destination: AccountInfo<'info>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+/// Result of a settled battle
+pub struct Settlement {
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+    pub winner_amount: u64,
+    pub platform_fee: u64,
+}
+
+/// Pay the platform fee and winnings out of escrow, close the escrow, release
+/// both Pokémon, record win/loss stats and experience, and mark the battle
+/// resolved.
+///
+/// The battle account itself is left open for the caller to close.
+pub fn settle_battle(accounts: SettleBattle, winner_is_player1: bool) -> Result<Settlement> {
+    let SettleBattle {
+        battle,
+        treasury,
+        battle_escrow,
+        mon_token_mint,
+        platform_fee_account,
+        player1_mon_account,
+        player2_mon_account,
+        player1_pokemon_data,
+        player2_pokemon_data,
+        player1_lock,
+        player2_lock,
+        escrow_rent_destination,
+        token_program,
+    } = accounts;
+
+    let total_pot = battle_escrow.amount;
+    let platform_fee = battle.platform_fee_amount;
+    let winner_amount = total_pot
+        .checked_sub(platform_fee)
+        .ok_or(GameError::MathOverflow)?;
+
+    let binding = battle.battle_id.to_le_bytes();
+    let battle_seeds = &[
+        b"battle".as_ref(),
+        binding.as_ref(),
+        &[battle.bump],
+    ];
+    let signer_seeds = &[&battle_seeds[..]];
+
+    // Transfer platform fee
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: battle_escrow.to_account_info(),
+                mint: mon_token_mint.to_account_info(),
+                to: platform_fee_account.to_account_info(),
+                authority: battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        platform_fee,
+        mon_token_mint.decimals,
+    )?;
+
+    msg!("Platform fee collected: {}", platform_fee);
+
+    // Transfer winnings to winner
+    let winner_account = if winner_is_player1 {
+        player1_mon_account
+    } else {
+        player2_mon_account
+    };
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: battle_escrow.to_account_info(),
+                mint: mon_token_mint.to_account_info(),
+                to: winner_account.to_account_info(),
+                authority: battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        winner_amount,
+        mon_token_mint.decimals,
+    )?;
+
+    msg!("Winner received: {} MON tokens", winner_amount);
+
+    // Close escrow account
+    close_account(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: battle_escrow.to_account_info(),
+                destination: escrow_rent_destination,
+                authority: battle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+    )?;
+
+    // Release both Pokemon from the battle
+    pokemon_lock::unlock_pokemon(player1_lock, player1_pokemon_data)?;
+    pokemon_lock::unlock_pokemon(player2_lock, player2_pokemon_data)?;
+
+    // Update Pokemon stats
+    let current_time = Clock::get()?.unix_timestamp;
+    let (winner_data, loser_data) = if winner_is_player1 {
+        (player1_pokemon_data, player2_pokemon_data)
+    } else {
+        (player2_pokemon_data, player1_pokemon_data)
+    };
+
+    winner_data.battles_won = winner_data.battles_won
+        .checked_add(1)
+        .ok_or(GameError::MathOverflow)?;
+    winner_data.last_battle_at = current_time;
+
+    loser_data.battles_lost = loser_data.battles_lost
+        .checked_add(1)
+        .ok_or(GameError::MathOverflow)?;
+    loser_data.last_battle_at = current_time;
+
+    // Award experience: the winner earns more, the loser still progresses
+    for (pokemon_data, experience) in [
+        (winner_data, leveling::WIN_EXPERIENCE),
+        (loser_data, leveling::LOSS_EXPERIENCE),
+    ] {
+        if leveling::award_experience(pokemon_data, experience) > 0 {
+            msg!("Pokemon {} reached level {}", pokemon_data.mint, pokemon_data.level);
+
+            emit!(PokemonLeveledUp {
+                mint: pokemon_data.mint,
+                level: pokemon_data.level,
+                experience: pokemon_data.experience,
+            });
+        }
+    }
+
+    // Update battle status
+    let player2 = battle.player2.ok_or(GameError::BattleNotReady)?;
+    let (winner, loser) = if winner_is_player1 {
+        (battle.player1, player2)
+    } else {
+        (player2, battle.player1)
+    };
+    battle.status = BattleStatus::Resolved;
+    battle.winner = Some(winner);
+    battle.resolved_at = Some(current_time);
+
+    // Update treasury
+    treasury.total_fees_collected = treasury.total_fees_collected
+        .checked_add(platform_fee)
+        .ok_or(GameError::MathOverflow)?;
+    treasury.total_battle_fees = treasury.total_battle_fees
+        .checked_add(platform_fee)
+        .ok_or(GameError::MathOverflow)?;
+    treasury.mon_balance = treasury.mon_balance
+        .checked_add(platform_fee)
+        .ok_or(GameError::MathOverflow)?;
+
+    msg!(
+        "Battle {} resolved. Winner: {}",
+        battle.battle_id,
+        if winner_is_player1 { "Player 1" } else { "Player 2" }
+    );
+
+    Ok(Settlement {
+        winner,
+        loser,
+        winner_amount,
+        platform_fee,
+    })
+}
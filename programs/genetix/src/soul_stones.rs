@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use crate::{state::*, errors::GameError, SECONDS_PER_DAY};
+
+/// A wallet's Soul Stone purchases for the current day, including the one
+/// being made
+pub struct DailyPurchase {
+    pub day: i64,
+    pub purchased_today: u64,
+}
+
+/// Accounts touched when delivering purchased Soul Stones
+pub struct DeliverSoulStones<'a, 'info> {
+    pub player: Pubkey,
+    pub platform: &'a Account<'info, Platform>,
+    pub soul_stone_config: &'a mut Account<'info, SoulStoneConfig>,
+    pub soul_stone_mint: &'a Account<'info, Mint>,
+    pub player_soul_stone_account: &'a Account<'info, TokenAccount>,
+    pub purchase_record: &'a mut Account<'info, SoulStonePurchaseRecord>,
+    pub purchase_record_bump: u8,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+/// Enforce the per-wallet daily cap, shared by SOL and MON purchases
+pub fn check_daily_cap(
+    config: &SoulStoneConfig,
+    purchase_record: &SoulStonePurchaseRecord,
+    quantity: u64,
+) -> Result<DailyPurchase> {
+    let day = Clock::get()?.unix_timestamp / SECONDS_PER_DAY;
+    let purchased_today = purchase_record
+        .purchased_on(day)
+        .checked_add(quantity)
+        .ok_or(GameError::MathOverflow)?;
+    if config.daily_cap_per_wallet > 0 {
+        require!(
+            purchased_today <= config.daily_cap_per_wallet,
+            GameError::DailyCapExceeded
+        );
+    }
+
+    Ok(DailyPurchase { day, purchased_today })
+}
+
+/// Mint paid-for Soul Stones to the player, then update the supply counter
+/// and the wallet's daily purchase record.
+///
+/// Payment must already have been taken.
+pub fn deliver_soul_stones(
+    accounts: DeliverSoulStones,
+    quantity: u64,
+    daily_purchase: DailyPurchase,
+) -> Result<()> {
+    let DeliverSoulStones {
+        player,
+        platform,
+        soul_stone_config,
+        soul_stone_mint,
+        player_soul_stone_account,
+        purchase_record,
+        purchase_record_bump,
+        token_program,
+    } = accounts;
+
+    let platform_seeds = &[
+        b"platform".as_ref(),
+        &[platform.bump],
+    ];
+    let signer_seeds = &[&platform_seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: soul_stone_mint.to_account_info(),
+                to: player_soul_stone_account.to_account_info(),
+                authority: platform.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        quantity,
+    )?;
+
+    // Update counters
+    soul_stone_config.total_minted = soul_stone_config.total_minted
+        .checked_add(quantity)
+        .ok_or(GameError::MathOverflow)?;
+
+    purchase_record.player = player;
+    purchase_record.day = daily_purchase.day;
+    purchase_record.purchased_today = daily_purchase.purchased_today;
+    purchase_record.bump = purchase_record_bump;
+
+    msg!("{} Soul Stone(s) minted successfully to player", quantity);
+
+    Ok(())
+}
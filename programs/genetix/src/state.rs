@@ -1,19 +1,47 @@
 use anchor_lang::prelude::*;
+use crate::{ELEMENT_TYPE_COUNT, TYPE_CHART_SIZE, TYPE_NEUTRAL_BPS};
 
 /// Platform configuration and settings
 #[account]
 #[derive(InitSpace)]
 pub struct Platform {
     pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // Set by propose_admin, cleared by accept_admin
     pub mon_token_mint: Pubkey,
     pub soul_stone_mint: Pubkey,
+    pub collection_mint: Pubkey, // Verified Genetix collection NFT
     pub soul_stone_price_lamports: u64,
     pub platform_fee_percentage: u16, // Basis points (e.g., 500 = 5%)
     pub total_pokemon_minted: u64,
     pub total_battles: u64,
+    pub battle_timeout_seconds: i64, // In-progress battles idle this long can be expired
+    pub reveal_timeout_seconds: i64, // Window to commit or reveal once the opponent has
+    pub min_battle_stake: u64,
+    pub max_battle_stake: u64,
+    pub pause_flags: u8, // Bitmask of PAUSE_* features currently disabled
+    pub battles_paused_at: Option<i64>, // Set while PAUSE_BATTLES is on
+    pub battle_pause_seconds: i64, // Total time battles have spent paused
+    pub randomness_oracle: Pubkey, // Owner of accepted randomness accounts
     pub bump: u8,
 }
 
+impl Platform {
+    pub fn is_paused(&self, feature: u8) -> bool {
+        self.pause_flags & feature != 0
+    }
+
+    /// True for the master admin or a member holding `role`
+    pub fn has_authority(
+        &self,
+        authority: &Pubkey,
+        role_registry: Option<&RoleRegistry>,
+        role: Role,
+    ) -> bool {
+        self.admin == *authority
+            || role_registry.is_some_and(|registry| registry.has_role(authority, role))
+    }
+}
+
 /// Pokémon template in the marketplace
 #[account]
 #[derive(InitSpace)]
@@ -30,6 +58,52 @@ pub struct PokemonTemplate {
     pub speed: u16,
     pub is_active: bool,
     pub times_minted: u64,
+    pub max_supply: Option<u64>,
+    pub mint_start_ts: Option<i64>,
+    pub mint_end_ts: Option<i64>,
+    pub collection_mint: Option<Pubkey>, // Optional sub-collection for this template
+    pub primary_type: ElementType,
+    pub secondary_type: Option<ElementType>,
+    pub bump: u8,
+}
+
+/// Elemental type of a template, copied to each Pokémon at mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ElementType {
+    Normal,
+    Fire,
+    Water,
+    Grass,
+    Electric,
+    Ice,
+    Fighting,
+    Poison,
+    Ground,
+    Flying,
+    Psychic,
+    Bug,
+    Rock,
+    Ghost,
+    Dragon,
+    Dark,
+    Steel,
+    Fairy,
+}
+
+/// Evolution step for a template, keyed by (template_id, evolution_stage)
+#[account]
+#[derive(InitSpace)]
+pub struct EvolutionRule {
+    pub template_id: u64,
+    pub evolution_stage: u8, // Stage reached by applying this rule
+    #[max_len(200)]
+    pub metadata_uri: String,
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub soul_stone_cost: u64,
+    pub min_level: u8, // 0 = no level requirement
     pub bump: u8,
 }
 
@@ -48,15 +122,56 @@ pub struct PokemonData {
     pub attack: u16,
     pub defense: u16,
     pub speed: u16,
+    pub primary_type: ElementType,
+    pub secondary_type: Option<ElementType>,
     pub level: u8,
+    pub experience: u64, // Total XP earned, drives `level`
+    #[max_len(4)]
+    pub moves: Vec<KnownMove>, // Equipped moves, at most MAX_MOVES_PER_POKEMON
     pub evolution_stage: u8,
     pub battles_won: u32,
     pub battles_lost: u32,
     pub created_at: i64,
     pub last_battle_at: i64,
+    pub active_battle: Option<Pubkey>, // Battle this Pokémon is locked into
+    pub bump: u8,
+}
+
+/// Move in the catalog, learnable by Pokémon that meet its requirements
+#[account]
+#[derive(InitSpace)]
+pub struct Move {
+    pub move_id: u64,
+    #[max_len(32)]
+    pub name: String,
+    pub power: u16,
+    pub accuracy: u8, // Percent chance to hit (1-100)
+    pub element_type: ElementType,
+    pub pp: u8, // Uses per battle
+    pub min_level: u8,
+    pub template_id: Option<u64>, // None = learnable by any template
     pub bump: u8,
 }
 
+/// Snapshot of a catalog move equipped on a Pokémon
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct KnownMove {
+    pub move_id: u64,
+    pub power: u16,
+    pub accuracy: u8,
+    pub element_type: ElementType,
+    pub pp: u8,
+}
+
+/// Combat stats of a catalog move, passed to `add_move`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MoveStats {
+    pub power: u16,
+    pub accuracy: u8, // Percent chance to hit, 1-100
+    pub element_type: ElementType,
+    pub pp: u8,
+}
+
 /// PvP Battle escrow
 #[account]
 #[derive(InitSpace)]
@@ -66,15 +181,125 @@ pub struct Battle {
     pub player2: Option<Pubkey>,
     pub player1_pokemon: Pubkey,
     pub player2_pokemon: Option<Pubkey>,
-    pub stake_amount: u64, // 10 MON tokens per player
+    pub stake_amount: u64, // Per player, chosen by player 1 within platform limits
     pub platform_fee_amount: u64,
     pub status: BattleStatus,
     pub winner: Option<Pubkey>,
     pub created_at: i64,
+    pub joined_at: Option<i64>,
     pub resolved_at: Option<i64>,
+    pub seed: [u8; 32], // Committed at creation, drives the battle simulation
+    pub mode: BattleMode,
+    pub terms: ChallengeTerms, // Who may join, enforced by join_battle
+    // Turn-based match state, set when player 2 joins
+    pub player1_hp: u16,
+    pub player2_hp: u16,
+    pub turn: u16, // Turns played so far
+    pub player1_pp: [u8; 4], // Remaining PP per equipped move slot
+    pub player2_pp: [u8; 4],
+    pub player1_commitment: Option<[u8; 32]>, // Hash of the hidden action for this turn
+    pub player2_commitment: Option<[u8; 32]>,
+    pub player1_action: Option<BattleAction>, // Revealed action for this turn
+    pub player2_action: Option<BattleAction>,
+    pub player1_salt: Option<[u8; 32]>, // Revealed salt, mixed into this turn's rolls
+    pub player2_salt: Option<[u8; 32]>,
+    pub turn_deadline: Option<i64>, // Missing it lets the opponent claim a forfeit
+    pub last_turn_at: Option<i64>, // Last commit or reveal, expiry counts from here
+    pub turn_pause_seconds: i64, // Platform's battle_pause_seconds at the join or last commit/reveal
+    pub randomness_account: Option<Pubkey>, // Committed by player 2 at join
+    pub randomness_request_slot: u64, // Request the randomness account held at join
+    pub randomness: Option<[u8; 32]>, // Oracle value mixed into `seed`, kept for audits
     pub bump: u8,
 }
 
+impl Battle {
+    /// Remaining PP for each of a Pokémon's equipped move slots
+    pub fn starting_pp(pokemon: &PokemonData) -> [u8; 4] {
+        let mut pp = [0u8; 4];
+        for (slot, known_move) in pokemon.moves.iter().enumerate().take(pp.len()) {
+            pp[slot] = known_move.pp;
+        }
+        pp
+    }
+
+    /// Turn deadline pushed back by any time battles spent paused since it
+    /// was set
+    pub fn effective_turn_deadline(&self, platform: &Platform) -> Option<i64> {
+        let paused = platform.battle_pause_seconds.saturating_sub(self.turn_pause_seconds);
+        self.turn_deadline.map(|deadline| deadline.saturating_add(paused))
+    }
+
+    /// Time after which an idle battle can be expired, pushed back by any
+    /// time battles spent paused since the join or last commit/reveal
+    pub fn idle_deadline(&self, platform: &Platform) -> Option<i64> {
+        let paused = platform.battle_pause_seconds.saturating_sub(self.turn_pause_seconds);
+        self.last_turn_at.or(self.joined_at).map(|last| {
+            last.saturating_add(platform.battle_timeout_seconds)
+                .saturating_add(paused)
+        })
+    }
+
+    /// Record a commit or reveal at `now`. Pause time owed to the current
+    /// deadline is folded into it before the pause snapshot moves forward
+    pub fn record_turn_activity(&mut self, platform: &Platform, now: i64) {
+        self.turn_deadline = self.effective_turn_deadline(platform);
+        self.turn_pause_seconds = platform.battle_pause_seconds;
+        self.last_turn_at = Some(now);
+    }
+
+    /// Clear commitments and reveals before the next turn
+    pub fn reset_turn(&mut self) {
+        self.player1_commitment = None;
+        self.player2_commitment = None;
+        self.player1_action = None;
+        self.player2_action = None;
+        self.player1_salt = None;
+        self.player2_salt = None;
+        self.turn_deadline = None;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BattleMode {
+    Simulated, // Resolved in one step by a registered resolver
+    TurnBased, // Players commit and reveal actions each turn until one side faints
+}
+
+/// Base stats of a template or evolution stage, passed to the admin
+/// instructions that set them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BaseStats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+}
+
+/// Restrictions player 1 places on who can join a battle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct ChallengeTerms {
+    pub invited_opponent: Option<Pubkey>, // None = open challenge
+    pub min_opponent_level: Option<u8>,
+    pub min_opponent_evolution_stage: Option<u8>,
+}
+
+/// Action chosen by a player on their turn
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BattleAction {
+    Attack,
+    UseMove { slot: u8 },
+}
+
+impl BattleAction {
+    /// Encoding hashed into turn commitments
+    pub fn to_bytes(self) -> [u8; 2] {
+        match self {
+            BattleAction::Attack => [0, 0],
+            BattleAction::UseMove { slot } => [1, slot],
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum BattleStatus {
     WaitingForPlayer2,
@@ -83,6 +308,19 @@ pub enum BattleStatus {
     Cancelled,
 }
 
+/// Local stand-in for a Switchboard randomness request, fulfilled by the admin.
+/// Only built with the `localnet` feature
+#[cfg(feature = "localnet")]
+#[account]
+#[derive(InitSpace)]
+pub struct LocalRandomness {
+    pub randomness_id: u64,
+    pub requested_slot: u64,
+    pub value: [u8; 32],
+    pub revealed: bool,
+    pub bump: u8,
+}
+
 /// Soul Stone minting configuration
 #[account]
 #[derive(InitSpace)]
@@ -90,14 +328,514 @@ pub struct SoulStoneConfig {
     pub mint: Pubkey,
     pub price_in_lamports: u64,
     pub total_minted: u64,
+    #[max_len(5)]
+    pub bulk_tiers: Vec<BulkDiscountTier>, // Sorted by ascending min_quantity
+    pub daily_cap_per_wallet: u64, // 0 = no cap
+    pub price_in_mon: Option<u64>, // None disables MON purchases
+    pub mon_payment_mode: MonPaymentMode,
+    pub total_mon_burned: u64,
+    pub bump: u8,
+}
+
+impl SoulStoneConfig {
+    /// Total price for `quantity` stones after the best applicable bulk discount
+    pub fn bulk_price(&self, unit_price: u64, quantity: u64) -> Option<u64> {
+        let discount_bps = self
+            .bulk_tiers
+            .iter()
+            .filter(|tier| quantity >= tier.min_quantity)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0);
+
+        (unit_price as u128)
+            .checked_mul(quantity as u128)?
+            .checked_mul(10000u128.checked_sub(discount_bps as u128)?)?
+            .checked_div(10000)
+            .and_then(|x| u64::try_from(x).ok())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct BulkDiscountTier {
+    pub min_quantity: u64,
+    pub discount_bps: u16, // Basis points off the total (e.g., 500 = 5%)
+}
+
+/// What happens to MON paid for Soul Stones
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MonPaymentMode {
+    Burn,
+    Treasury,
+}
+
+/// Per-wallet Soul Stone purchases for the daily cap
+#[account]
+#[derive(InitSpace)]
+pub struct SoulStonePurchaseRecord {
+    pub player: Pubkey,
+    pub day: i64, // Unix timestamp / SECONDS_PER_DAY
+    pub purchased_today: u64,
     pub bump: u8,
 }
 
+impl SoulStonePurchaseRecord {
+    /// Stones bought on `day`, zero once a new day has started
+    pub fn purchased_on(&self, day: i64) -> u64 {
+        if self.day == day { self.purchased_today } else { 0 }
+    }
+}
+
 /// Platform treasury for fee collection
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformTreasury {
-    pub total_fees_collected: u64,
-    pub mon_token_vault: Pubkey,
+    pub total_fees_collected: u64, // All MON revenue: mint revenue + battle fees + Soul Stone sales
+    pub mon_token_vault: Pubkey, // Platform's MON associated token account
+    pub total_mint_revenue: u64,
+    pub total_battle_fees: u64,
+    pub total_soul_stone_mon_revenue: u64,
+    pub total_mon_withdrawn: u64,
+    pub mon_balance: u64, // Tracked MON currently held in the vault
+    pub total_sol_collected: u64, // Soul Stone revenue held in this PDA
+    pub total_sol_withdrawn: u64,
+    #[max_len(5)]
+    pub revenue_splits: Vec<RevenueSplit>, // Empty means everything goes to the admin
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct RevenueSplit {
+    pub recipient: Pubkey,
+    pub share_bps: u16, // Basis points, all shares sum to 10000
+}
+
+/// Oracles authorized to resolve battles
+#[account]
+#[derive(InitSpace)]
+pub struct ResolverRegistry {
+    #[max_len(10)]
+    pub resolvers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+/// Type effectiveness multipliers, indexed [attacking][defending]
+#[account]
+#[derive(InitSpace)]
+pub struct TypeChart {
+    pub multipliers: [u16; TYPE_CHART_SIZE], // Basis points, 10000 = neutral
+    pub bump: u8,
+}
+
+impl TypeChart {
+    fn index(attacking: ElementType, defending: ElementType) -> usize {
+        attacking as usize * ELEMENT_TYPE_COUNT + defending as usize
+    }
+
+    pub fn multiplier(&self, attacking: ElementType, defending: ElementType) -> u16 {
+        self.multipliers[Self::index(attacking, defending)]
+    }
+
+    pub fn set_multiplier(&mut self, attacking: ElementType, defending: ElementType, multiplier_bps: u16) {
+        self.multipliers[Self::index(attacking, defending)] = multiplier_bps;
+    }
+
+    /// Combined multiplier in basis points against one or two defending types
+    pub fn effectiveness(
+        &self,
+        attacking: ElementType,
+        defending_primary: ElementType,
+        defending_secondary: Option<ElementType>,
+    ) -> u64 {
+        let primary = self.multiplier(attacking, defending_primary) as u64;
+        match defending_secondary {
+            Some(secondary) => {
+                primary * self.multiplier(attacking, secondary) as u64 / TYPE_NEUTRAL_BPS as u64
+            }
+            None => primary,
+        }
+    }
+}
+
+/// Delegated admin roles
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    FeeManager,
+    TemplateCurator,
+    RewardsIssuer,
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct RoleMember {
+    pub authority: Pubkey,
+    pub roles: u8, // Bitmask of Role::mask values
+}
+
+/// Role table for delegated admin permissions
+#[account]
+#[derive(InitSpace)]
+pub struct RoleRegistry {
+    #[max_len(16)]
+    pub members: Vec<RoleMember>,
     pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl RoleRegistry {
+    pub fn has_role(&self, authority: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.authority == *authority && member.roles & role.mask() != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn soul_stone_config(bulk_tiers: Vec<BulkDiscountTier>) -> SoulStoneConfig {
+        SoulStoneConfig {
+            mint: Pubkey::default(),
+            price_in_lamports: 1_000,
+            total_minted: 0,
+            bulk_tiers,
+            daily_cap_per_wallet: 0,
+            price_in_mon: None,
+            mon_payment_mode: MonPaymentMode::Burn,
+            total_mon_burned: 0,
+            bump: 0,
+        }
+    }
+
+    fn tier(min_quantity: u64, discount_bps: u16) -> BulkDiscountTier {
+        BulkDiscountTier { min_quantity, discount_bps }
+    }
+
+    #[test]
+    fn bulk_price_without_tiers_is_unit_price_times_quantity() {
+        let config = soul_stone_config(Vec::new());
+        assert_eq!(config.bulk_price(1_000, 1), Some(1_000));
+        assert_eq!(config.bulk_price(1_000, 7), Some(7_000));
+        assert_eq!(config.bulk_price(1_000, 0), Some(0));
+    }
+
+    #[test]
+    fn bulk_price_applies_tier_from_its_min_quantity() {
+        let config = soul_stone_config(vec![tier(10, 1_000)]);
+        assert_eq!(config.bulk_price(1_000, 9), Some(9_000));
+        assert_eq!(config.bulk_price(1_000, 10), Some(9_000));
+        assert_eq!(config.bulk_price(1_000, 11), Some(9_900));
+    }
+
+    #[test]
+    fn bulk_price_uses_best_applicable_tier() {
+        let config = soul_stone_config(vec![tier(5, 500), tier(10, 1_000), tier(50, 2_500)]);
+        assert_eq!(config.bulk_price(100, 4), Some(400));
+        assert_eq!(config.bulk_price(100, 5), Some(475));
+        assert_eq!(config.bulk_price(100, 49), Some(4_410));
+        assert_eq!(config.bulk_price(100, 50), Some(3_750));
+    }
+
+    #[test]
+    fn bulk_price_rounds_down() {
+        let config = soul_stone_config(vec![tier(1, 3_333)]);
+        // 3 * 6667 / 10000 = 2.0001
+        assert_eq!(config.bulk_price(3, 1), Some(2));
+    }
+
+    #[test]
+    fn bulk_price_rejects_overflow() {
+        let config = soul_stone_config(Vec::new());
+        assert_eq!(config.bulk_price(u64::MAX, 1), Some(u64::MAX));
+        assert_eq!(config.bulk_price(u64::MAX, 2), None);
+        assert_eq!(config.bulk_price(u64::MAX, u64::MAX), None);
+
+        // A discount can bring an oversized total back into range
+        let config = soul_stone_config(vec![tier(2, 5_000)]);
+        assert_eq!(config.bulk_price(u64::MAX, 2), Some(u64::MAX));
+    }
+
+    #[test]
+    fn bulk_price_rejects_discount_above_full_price() {
+        let config = soul_stone_config(vec![tier(1, 10_001)]);
+        assert_eq!(config.bulk_price(1_000, 1), None);
+
+        let config = soul_stone_config(vec![tier(1, 10_000)]);
+        assert_eq!(config.bulk_price(1_000, 1), Some(0));
+    }
+
+    fn neutral_type_chart() -> TypeChart {
+        TypeChart {
+            multipliers: [TYPE_NEUTRAL_BPS; TYPE_CHART_SIZE],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn set_multiplier_only_changes_its_pairing() {
+        let mut chart = neutral_type_chart();
+        chart.set_multiplier(ElementType::Fire, ElementType::Grass, 20_000);
+
+        assert_eq!(chart.multiplier(ElementType::Fire, ElementType::Grass), 20_000);
+        assert_eq!(chart.multiplier(ElementType::Grass, ElementType::Fire), TYPE_NEUTRAL_BPS);
+        assert_eq!(chart.multiplier(ElementType::Fire, ElementType::Water), TYPE_NEUTRAL_BPS);
+        assert_eq!(chart.multipliers.iter().filter(|&&bps| bps != TYPE_NEUTRAL_BPS).count(), 1);
+    }
+
+    #[test]
+    fn multiplier_covers_first_and_last_types() {
+        let mut chart = neutral_type_chart();
+        chart.set_multiplier(ElementType::Normal, ElementType::Normal, 1);
+        chart.set_multiplier(ElementType::Fairy, ElementType::Fairy, 2);
+        chart.set_multiplier(ElementType::Normal, ElementType::Fairy, 3);
+        chart.set_multiplier(ElementType::Fairy, ElementType::Normal, 4);
+
+        assert_eq!(chart.multipliers[0], 1);
+        assert_eq!(chart.multipliers[TYPE_CHART_SIZE - 1], 2);
+        assert_eq!(chart.multiplier(ElementType::Normal, ElementType::Fairy), 3);
+        assert_eq!(chart.multiplier(ElementType::Fairy, ElementType::Normal), 4);
+    }
+
+    #[test]
+    fn effectiveness_against_single_type_is_its_multiplier() {
+        let mut chart = neutral_type_chart();
+        chart.set_multiplier(ElementType::Water, ElementType::Fire, 20_000);
+
+        assert_eq!(chart.effectiveness(ElementType::Water, ElementType::Fire, None), 20_000);
+        assert_eq!(
+            chart.effectiveness(ElementType::Water, ElementType::Grass, None),
+            TYPE_NEUTRAL_BPS as u64
+        );
+    }
+
+    #[test]
+    fn effectiveness_against_dual_type_multiplies_both() {
+        let mut chart = neutral_type_chart();
+        chart.set_multiplier(ElementType::Ice, ElementType::Dragon, 20_000);
+        chart.set_multiplier(ElementType::Ice, ElementType::Flying, 20_000);
+        chart.set_multiplier(ElementType::Ice, ElementType::Steel, 5_000);
+        chart.set_multiplier(ElementType::Electric, ElementType::Ground, 0);
+
+        assert_eq!(
+            chart.effectiveness(ElementType::Ice, ElementType::Dragon, Some(ElementType::Flying)),
+            40_000
+        );
+        assert_eq!(
+            chart.effectiveness(ElementType::Ice, ElementType::Dragon, Some(ElementType::Steel)),
+            TYPE_NEUTRAL_BPS as u64
+        );
+        assert_eq!(
+            chart.effectiveness(ElementType::Electric, ElementType::Water, Some(ElementType::Ground)),
+            0
+        );
+    }
+
+    #[test]
+    fn effectiveness_does_not_overflow_at_max_multipliers() {
+        let chart = TypeChart {
+            multipliers: [u16::MAX; TYPE_CHART_SIZE],
+            bump: 0,
+        };
+        assert_eq!(
+            chart.effectiveness(ElementType::Fire, ElementType::Fire, Some(ElementType::Fire)),
+            u16::MAX as u64 * u16::MAX as u64 / TYPE_NEUTRAL_BPS as u64
+        );
+    }
+
+    fn role_registry(members: &[(Pubkey, &[Role])]) -> RoleRegistry {
+        RoleRegistry {
+            members: members
+                .iter()
+                .map(|(authority, roles)| RoleMember {
+                    authority: *authority,
+                    roles: roles.iter().fold(0, |mask, role| mask | role.mask()),
+                })
+                .collect(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn role_masks_are_distinct_bits() {
+        let masks = [Role::FeeManager, Role::TemplateCurator, Role::RewardsIssuer].map(Role::mask);
+        assert_eq!(masks, [0b001, 0b010, 0b100]);
+    }
+
+    #[test]
+    fn has_role_only_for_granted_roles() {
+        let curator = Pubkey::new_unique();
+        let registry = role_registry(&[(curator, &[Role::TemplateCurator])]);
+
+        assert!(registry.has_role(&curator, Role::TemplateCurator));
+        assert!(!registry.has_role(&curator, Role::FeeManager));
+        assert!(!registry.has_role(&curator, Role::RewardsIssuer));
+    }
+
+    #[test]
+    fn has_role_with_several_roles() {
+        let manager = Pubkey::new_unique();
+        let registry = role_registry(&[(manager, &[Role::FeeManager, Role::RewardsIssuer])]);
+
+        assert!(registry.has_role(&manager, Role::FeeManager));
+        assert!(registry.has_role(&manager, Role::RewardsIssuer));
+        assert!(!registry.has_role(&manager, Role::TemplateCurator));
+    }
+
+    #[test]
+    fn has_role_is_per_member() {
+        let curator = Pubkey::new_unique();
+        let issuer = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+        let registry = role_registry(&[
+            (curator, &[Role::TemplateCurator]),
+            (issuer, &[Role::RewardsIssuer]),
+        ]);
+
+        assert!(!registry.has_role(&curator, Role::RewardsIssuer));
+        assert!(!registry.has_role(&issuer, Role::TemplateCurator));
+        assert!(!registry.has_role(&stranger, Role::TemplateCurator));
+        assert!(!role_registry(&[]).has_role(&curator, Role::TemplateCurator));
+    }
+
+    #[test]
+    fn member_without_roles_has_none() {
+        let former = Pubkey::new_unique();
+        let registry = role_registry(&[(former, &[])]);
+
+        for role in [Role::FeeManager, Role::TemplateCurator, Role::RewardsIssuer] {
+            assert!(!registry.has_role(&former, role));
+        }
+    }
+
+    fn platform(battle_pause_seconds: i64) -> Platform {
+        Platform {
+            admin: Pubkey::default(),
+            pending_admin: None,
+            mon_token_mint: Pubkey::default(),
+            soul_stone_mint: Pubkey::default(),
+            collection_mint: Pubkey::default(),
+            soul_stone_price_lamports: 0,
+            platform_fee_percentage: 0,
+            total_pokemon_minted: 0,
+            total_battles: 0,
+            battle_timeout_seconds: 3_600,
+            reveal_timeout_seconds: 300,
+            min_battle_stake: 0,
+            max_battle_stake: 0,
+            pause_flags: 0,
+            battles_paused_at: None,
+            battle_pause_seconds,
+            randomness_oracle: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    fn battle(turn_deadline: Option<i64>, turn_pause_seconds: i64) -> Battle {
+        Battle {
+            battle_id: 0,
+            player1: Pubkey::default(),
+            player2: None,
+            player1_pokemon: Pubkey::default(),
+            player2_pokemon: None,
+            stake_amount: 0,
+            platform_fee_amount: 0,
+            status: BattleStatus::InProgress,
+            winner: None,
+            created_at: 0,
+            joined_at: Some(1_000),
+            resolved_at: None,
+            seed: [0; 32],
+            mode: BattleMode::TurnBased,
+            terms: ChallengeTerms {
+                invited_opponent: None,
+                min_opponent_level: None,
+                min_opponent_evolution_stage: None,
+            },
+            player1_hp: 0,
+            player2_hp: 0,
+            turn: 0,
+            player1_pp: [0; 4],
+            player2_pp: [0; 4],
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_action: None,
+            player2_action: None,
+            player1_salt: None,
+            player2_salt: None,
+            turn_deadline,
+            last_turn_at: None,
+            turn_pause_seconds,
+            randomness_account: None,
+            randomness_request_slot: 0,
+            randomness: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn effective_turn_deadline_without_deadline_is_none() {
+        assert_eq!(battle(None, 0).effective_turn_deadline(&platform(0)), None);
+        assert_eq!(battle(None, 0).effective_turn_deadline(&platform(500)), None);
+    }
+
+    #[test]
+    fn effective_turn_deadline_without_pause_is_unchanged() {
+        assert_eq!(battle(Some(2_000), 0).effective_turn_deadline(&platform(0)), Some(2_000));
+        // Pauses before the deadline was set are already behind it
+        assert_eq!(battle(Some(2_000), 700).effective_turn_deadline(&platform(700)), Some(2_000));
+    }
+
+    #[test]
+    fn effective_turn_deadline_extends_by_later_pauses() {
+        assert_eq!(battle(Some(2_000), 0).effective_turn_deadline(&platform(250)), Some(2_250));
+        assert_eq!(battle(Some(2_000), 100).effective_turn_deadline(&platform(350)), Some(2_250));
+    }
+
+    #[test]
+    fn effective_turn_deadline_saturates() {
+        assert_eq!(
+            battle(Some(i64::MAX - 10), 0).effective_turn_deadline(&platform(100)),
+            Some(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn idle_deadline_counts_from_last_turn_then_join() {
+        let platform = platform(0);
+        let mut battle = battle(None, 0);
+        assert_eq!(battle.idle_deadline(&platform), Some(1_000 + 3_600));
+
+        battle.last_turn_at = Some(1_500);
+        assert_eq!(battle.idle_deadline(&platform), Some(1_500 + 3_600));
+
+        battle.last_turn_at = None;
+        battle.joined_at = None;
+        assert_eq!(battle.idle_deadline(&platform), None);
+    }
+
+    #[test]
+    fn idle_deadline_extends_by_later_pauses() {
+        let mut battle = battle(None, 100);
+        battle.last_turn_at = Some(1_500);
+        assert_eq!(battle.idle_deadline(&platform(400)), Some(1_500 + 3_600 + 300));
+    }
+
+    #[test]
+    fn record_turn_activity_folds_pause_into_deadline() {
+        let platform = platform(250);
+        let mut battle = battle(Some(2_000), 0);
+        battle.record_turn_activity(&platform, 1_900);
+
+        assert_eq!(battle.turn_deadline, Some(2_250));
+        assert_eq!(battle.turn_pause_seconds, 250);
+        assert_eq!(battle.last_turn_at, Some(1_900));
+        // The same pause is not counted twice
+        assert_eq!(battle.effective_turn_deadline(&platform), Some(2_250));
+        assert_eq!(battle.idle_deadline(&platform), Some(1_900 + 3_600));
+    }
+}